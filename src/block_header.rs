@@ -0,0 +1,183 @@
+use sha3::{Digest, Sha3_256};
+use crate::error::IconError;
+use crate::logs_bloom::{address_from_bytes, decode_address, LogsBloom};
+use crate::rlp::{decode_field, Decodable, Encodable, RlpItem};
+use crate::rlp_struct;
+use crate::utils::helpers::decode_base64;
+use crate::wallet::recover_address;
+
+/// Vote type of the commit votes finalizing a block.
+pub const VOTE_TYPE_PRECOMMIT: i64 = 1;
+
+/// A block header as returned by `icx_getBlockHeaderByHeight`.
+///
+/// Hashes are raw bytes, empty when the node left them out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockHeader {
+    pub version: i64,
+    pub height: u64,
+    /// Microseconds since the epoch.
+    pub timestamp: u64,
+    pub proposer: Option<String>,
+    pub prev_hash: Vec<u8>,
+    pub votes_hash: Vec<u8>,
+    pub next_validators_hash: Vec<u8>,
+    pub patch_transactions_hash: Vec<u8>,
+    pub normal_transactions_hash: Vec<u8>,
+    pub logs_bloom: LogsBloom,
+    pub result: Vec<u8>,
+    bytes: Vec<u8>,
+}
+
+impl BlockHeader {
+    pub fn from_base64(value: &str) -> Result<Self, IconError> {
+        Self::from_bytes(&decode_base64(value)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IconError> {
+        let item = RlpItem::decode(bytes)?;
+        let fields = item.as_list()?;
+        if fields.len() < 11 {
+            return Err(IconError::Rlp(format!("block header has {} fields, expected 11", fields.len())));
+        }
+        let mut fields = fields.iter();
+
+        Ok(Self {
+            version: decode_field(&mut fields)?,
+            height: decode_field(&mut fields)?,
+            timestamp: decode_field(&mut fields)?,
+            proposer: decode_address(fields.next().unwrap_or(&RlpItem::Nil))?,
+            prev_hash: decode_field(&mut fields)?,
+            votes_hash: decode_field(&mut fields)?,
+            next_validators_hash: decode_field(&mut fields)?,
+            patch_transactions_hash: decode_field(&mut fields)?,
+            normal_transactions_hash: decode_field(&mut fields)?,
+            logs_bloom: decode_field(&mut fields)?,
+            result: decode_field(&mut fields)?,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// The serialized header, as received.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The block hash, SHA3-256 of the serialized header.
+    pub fn hash(&self) -> Vec<u8> {
+        Sha3_256::digest(&self.bytes).to_vec()
+    }
+
+    /// Decodes `result`, the outcome of executing the previous block's transactions.
+    pub fn block_result(&self) -> Result<BlockResult, IconError> {
+        BlockResult::from_rlp_bytes(&self.result)
+    }
+}
+
+/// Roots of the world state and receipts committed by a block header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockResult {
+    pub state_hash: Vec<u8>,
+    pub patch_receipt_hash: Vec<u8>,
+    pub normal_receipt_hash: Vec<u8>,
+    pub extension_data: Vec<u8>,
+}
+
+rlp_struct!(BlockResult { state_hash, patch_receipt_hash, normal_receipt_hash, extension_data });
+
+/// Decodes a validator list, as referenced by `next_validators_hash`: the addresses of the validators in order.
+pub fn decode_validators(bytes: &[u8]) -> Result<Vec<String>, IconError> {
+    Vec::<Vec<u8>>::from_rlp_bytes(bytes)?
+        .iter()
+        .map(|validator| address_from_bytes(validator))
+        .collect()
+}
+
+/// Identifies the parts a block was split into for propagation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartSetId {
+    pub count: u32,
+    pub hash: Vec<u8>,
+}
+
+rlp_struct!(PartSetId { count, hash });
+
+/// A validator's signature of a block, `signature` being `r || s || recovery_id`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitVote {
+    pub timestamp: i64,
+    pub signature: Vec<u8>,
+}
+
+rlp_struct!(CommitVote { timestamp, signature });
+
+/// The votes committing a block, as returned by `icx_getVotesByHeight`.
+///
+/// `items` follow the order of the validator set, `None` for validators that didn't vote.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitVotes {
+    pub round: i32,
+    pub block_part_set_id: Option<PartSetId>,
+    pub items: Vec<Option<CommitVote>>,
+}
+
+rlp_struct!(CommitVotes { round, block_part_set_id, items });
+
+// What validators sign, the SHA3-256 hash of its RLP encoding
+struct VoteMessage {
+    height: u64,
+    round: i32,
+    vote_type: i64,
+    block_id: Vec<u8>,
+    block_part_set_id: Option<PartSetId>,
+    timestamp: i64,
+}
+
+rlp_struct!(VoteMessage { height, round, vote_type, block_id, block_part_set_id, timestamp });
+
+impl CommitVotes {
+    pub fn from_base64(value: &str) -> Result<Self, IconError> {
+        Self::from_bytes(&decode_base64(value)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IconError> {
+        Self::from_rlp_bytes(bytes)
+    }
+
+    /// The message a validator signed for `vote` on the block of `header`.
+    pub fn vote_message(&self, header: &BlockHeader, vote: &CommitVote) -> Vec<u8> {
+        VoteMessage {
+            height: header.height,
+            round: self.round,
+            vote_type: VOTE_TYPE_PRECOMMIT,
+            block_id: header.hash(),
+            block_part_set_id: self.block_part_set_id.clone(),
+            timestamp: vote.timestamp,
+        }.rlp_bytes()
+    }
+
+    /// Checks that more than two thirds of `validators` signed the block of `header`.
+    ///
+    /// `validators` is the set listed by the `next_validators_hash` of the previous block, see
+    /// `IconService::get_validators_by_hash`.
+    pub fn verify(&self, header: &BlockHeader, validators: &[String]) -> Result<(), IconError> {
+        if self.items.len() > validators.len() {
+            return Err(IconError::Verification(format!("{} votes for {} validators", self.items.len(), validators.len())));
+        }
+
+        let signed = self.items.iter()
+            .zip(validators.iter())
+            .filter(|(vote, validator)| match vote {
+                Some(vote) => recover_address(&self.vote_message(header, vote), &vote.signature)
+                    .is_some_and(|signer| signer == **validator),
+                None => false,
+            })
+            .count();
+
+        if signed * 3 <= validators.len() * 2 {
+            return Err(IconError::Verification(format!("block {} signed by {} of {} validators", header.height, signed, validators.len())));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use futures_util::stream::{self, BoxStream};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use crate::error::IconError;
+use crate::icon_service::IconService;
+use crate::requests::{GetBlockByHeight, GetLastBlock};
+use crate::responses::Block;
+
+/// Where a block stream resumes: the next height to fetch and the hash of the block before it.
+///
+/// Persist `BlockCursor::after(&block)` once a block is processed to restart right after it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockCursor {
+    pub next_height: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_block_hash: Option<String>,
+}
+
+impl BlockCursor {
+    /// Starts at `height` without checking the parent of the first block.
+    pub fn new(height: u64) -> Self {
+        Self {
+            next_height: height,
+            last_block_hash: None,
+        }
+    }
+
+    /// Resumes right after `block`, which the next block must extend.
+    pub fn after(block: &Block) -> Self {
+        Self {
+            next_height: block.height + 1,
+            last_block_hash: Some(block.block_hash.clone()),
+        }
+    }
+
+    /// Reads a cursor saved with `save`, `None` if there is no file yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Option<Self>, IconError> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(Some(serde_json::from_str(&content)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Writes the cursor to `path`, going through a temporary file so a crash never leaves it truncated.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), IconError> {
+        let path = path.as_ref();
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+
+        fs::write(&temp, serde_json::to_vec(self)?)?;
+        fs::rename(&temp, path)?;
+
+        Ok(())
+    }
+
+    fn follows(&self, block: &Block) -> bool {
+        match &self.last_block_hash {
+            Some(hash) => strip_hex_prefix(hash).eq_ignore_ascii_case(strip_hex_prefix(&block.prev_block_hash)),
+            None => true,
+        }
+    }
+}
+
+struct Poller {
+    icon_service: IconService,
+    cursor: BlockCursor,
+    interval: Duration,
+    // Height of the last block known to exist, so catching up doesn't query the last block each time
+    last_height: Option<u64>,
+    // Set after an error so a failing node isn't queried in a tight loop
+    wait: bool,
+    done: bool,
+}
+
+/// Streams blocks in order from `cursor`, asking for the last block every `interval` once caught up.
+///
+/// Blocks are only fetched when the stream is polled, one at a time. Request errors are yielded
+/// and retried after `interval`; a block not extending the previous one ends the stream with
+/// `IconError::ChainMismatch`, as ICON blocks are final and the node must be on another chain.
+pub(crate) fn poll_blocks(icon_service: IconService, cursor: BlockCursor, interval: Duration) -> BoxStream<'static, Result<Block, IconError>> {
+    let poller = Poller {
+        icon_service,
+        cursor,
+        interval,
+        last_height: None,
+        wait: false,
+        done: false,
+    };
+
+    stream::unfold(poller, |mut poller| async move {
+        if poller.done {
+            return None;
+        }
+
+        loop {
+            if poller.wait {
+                tokio::time::sleep(poller.interval).await;
+                poller.wait = false;
+            }
+
+            let block = if poller.last_height.is_some_and(|height| height >= poller.cursor.next_height) {
+                poller.icon_service.fetch(&GetBlockByHeight { height: format!("0x{:x}", poller.cursor.next_height) }).await
+            } else {
+                match poller.icon_service.fetch(&GetLastBlock).await {
+                    // Already the block we need, no need to fetch it again
+                    Ok(block) if block.height == poller.cursor.next_height => Ok(block),
+                    Ok(block) => {
+                        poller.last_height = Some(block.height);
+                        // Nothing new yet
+                        poller.wait = block.height < poller.cursor.next_height;
+                        continue;
+                    },
+                    Err(e) => Err(e),
+                }
+            };
+
+            let block = match block {
+                Ok(block) => block,
+                Err(e) => {
+                    poller.wait = true;
+                    return Some((Err(e), poller));
+                },
+            };
+
+            if block.height != poller.cursor.next_height || !poller.cursor.follows(&block) {
+                poller.done = true;
+                return Some((Err(IconError::ChainMismatch { height: poller.cursor.next_height }), poller));
+            }
+
+            poller.cursor = BlockCursor::after(&block);
+            return Some((Ok(block), poller));
+        }
+    }).boxed()
+}
+
+fn strip_hex_prefix(value: &str) -> &str {
+    value.strip_prefix("0x").unwrap_or(value)
+}
@@ -0,0 +1,72 @@
+use crate::error::IconError;
+use crate::rlp::{decode_field, RlpItem};
+use crate::rlp_struct;
+use crate::utils::helpers::decode_base64;
+
+/// A sibling hash on the path from a network section to the root, `side` telling which side it
+/// is on (0 left, 1 right). Encoded like goloop's `MerkleNode`, direction first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashSide {
+    pub side: i64,
+    pub value: Vec<u8>,
+}
+
+rlp_struct!(HashSide { side, value });
+
+/// A BTP block header, as returned by `btp_getHeader` and the `/btp` websocket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BtpHeader {
+    pub main_height: u64,
+    pub round: i32,
+    pub next_proof_context_hash: Vec<u8>,
+    pub network_section_to_root: Vec<HashSide>,
+    pub network_id: u64,
+    pub update_number: i64,
+    pub prev_network_section_hash: Vec<u8>,
+    pub message_count: u64,
+    pub messages_root: Vec<u8>,
+    pub next_proof_context: Vec<u8>,
+    bytes: Vec<u8>,
+}
+
+impl BtpHeader {
+    pub fn from_base64(value: &str) -> Result<Self, IconError> {
+        Self::from_bytes(&decode_base64(value)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IconError> {
+        let item = RlpItem::decode(bytes)?;
+        let fields = item.as_list()?;
+        if fields.len() < 10 {
+            return Err(IconError::Rlp(format!("BTP header has {} fields, expected 10", fields.len())));
+        }
+        let mut fields = fields.iter();
+
+        Ok(Self {
+            main_height: decode_field(&mut fields)?,
+            round: decode_field(&mut fields)?,
+            next_proof_context_hash: decode_field(&mut fields)?,
+            network_section_to_root: decode_field(&mut fields)?,
+            network_id: decode_field(&mut fields)?,
+            update_number: decode_field(&mut fields)?,
+            prev_network_section_hash: decode_field(&mut fields)?,
+            message_count: decode_field(&mut fields)?,
+            messages_root: decode_field(&mut fields)?,
+            next_proof_context: decode_field(&mut fields)?,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// The serialized header, as received.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// A BTP block of the subscribed network, sent by the `/btp` websocket endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BtpNotification {
+    pub header: BtpHeader,
+    /// Present when the subscription asked for proofs.
+    pub proof: Option<Vec<u8>>,
+}
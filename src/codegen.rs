@@ -0,0 +1,266 @@
+use std::fmt::Write;
+use crate::responses::{ScoreApi, ScoreApiEntry, ScoreApiParam};
+
+const KEYWORDS: [&str; 48] = [
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "abstract", "become", "box", "do", "final", "gen", "macro", "override", "priv", "try",
+    "typeof", "unsized", "virtual", "yield",
+];
+
+// Keywords that can't be raw identifiers either
+const PATH_KEYWORDS: [&str; 3] = ["crate", "self", "super"];
+
+// Argument names used by the generated methods themselves
+const RESERVED_ARGS: [&str; 8] = ["wallet", "icx_value", "version", "nid", "nonce", "step_limit", "params", "result"];
+
+const SCORE_VALUE: &str = "icon_sdk::contract::ScoreValue";
+
+/// Generates Rust bindings for a SCORE from its ABI, for use in build scripts.
+///
+/// The output declares `struct_name` with an async method per SCORE function, readonly ones
+/// going through `icx_call` and writable ones through a signed `icx_sendTransaction`,
+/// plus a `<Name>Event` struct per event log that can be decoded from transaction results.
+pub fn generate_bindings(struct_name: &str, score_api: &ScoreApi) -> String {
+    let mut out = String::new();
+    let score_api_json = serde_json::to_string(score_api).expect("Failed to serialize SCORE API");
+
+    writeln!(out, "// Generated by icon_sdk::codegen. Do not edit.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub struct {} {{", struct_name).unwrap();
+    writeln!(out, "    contract: icon_sdk::contract::Contract,").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "impl {} {{", struct_name).unwrap();
+    writeln!(out, "    const SCORE_API: &'static str = r###\"{}\"###;", score_api_json).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    pub fn new(icon_service: icon_sdk::icon_service::IconService, contract_address: &str) -> Self {{").unwrap();
+    writeln!(out, "        let score_api = icon_sdk::responses::ScoreApi::from_json(Self::SCORE_API).expect(\"Invalid SCORE API\");").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "        Self {{").unwrap();
+    writeln!(out, "            contract: icon_sdk::contract::Contract::with_api(icon_service, contract_address, score_api),").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    pub fn contract(&self) -> &icon_sdk::contract::Contract {{").unwrap();
+    writeln!(out, "        &self.contract").unwrap();
+    writeln!(out, "    }}").unwrap();
+
+    for function in score_api.functions() {
+        writeln!(out).unwrap();
+        write_function(&mut out, function);
+    }
+
+    writeln!(out, "}}").unwrap();
+
+    for event in score_api.events() {
+        writeln!(out).unwrap();
+        write_event(&mut out, struct_name, event);
+    }
+
+    out
+}
+
+fn write_function(out: &mut String, function: &ScoreApiEntry) {
+    let fn_name = match to_snake_case(&function.name).as_str() {
+        "new" | "contract" => format!("{}_call", to_snake_case(&function.name)),
+        _ => to_ident(&function.name),
+    };
+
+    let mut args = vec!["&self".to_string()];
+    if !function.is_readonly() {
+        args.push("wallet: &icon_sdk::wallet::Wallet".to_string());
+    }
+    for input in &function.inputs {
+        let arg_type = arg_type(&input.param_type);
+        if input.is_optional() {
+            args.push(format!("{}: Option<{}>", arg_name(&input.name), arg_type));
+        } else {
+            args.push(format!("{}: {}", arg_name(&input.name), arg_type));
+        }
+    }
+
+    let return_type = if function.is_readonly() {
+        function.outputs.first()
+            .map(|output| owned_type(&output.param_type))
+            .unwrap_or_else(|| SCORE_VALUE.to_string())
+    } else {
+        args.extend(function.is_payable().then(|| "icx_value: &str".to_string()));
+        args.extend(["version: &str", "nid: Option<&str>", "nonce: &str", "step_limit: &str"].map(String::from));
+        "icon_sdk::serde_json::Value".to_string()
+    };
+
+    if args.len() > 7 {
+        writeln!(out, "    #[allow(clippy::too_many_arguments)]").unwrap();
+    }
+    writeln!(out, "    pub async fn {}({}) -> Result<{}, Box<dyn std::error::Error>> {{", fn_name, args.join(", "), return_type).unwrap();
+
+    let (optional, required): (Vec<&ScoreApiParam>, Vec<&ScoreApiParam>) = function.inputs.iter().partition(|input| input.is_optional());
+    let params = if function.inputs.is_empty() {
+        "&[]"
+    } else {
+        let binding = if optional.is_empty() { "let" } else { "let mut" };
+        writeln!(out, "        {} params: Vec<(&str, {})> = vec![", binding, SCORE_VALUE).unwrap();
+        for input in &required {
+            writeln!(out, "            (\"{}\", {}),", input.name, to_score_value(&arg_name(&input.name), &input.param_type, false)).unwrap();
+        }
+        writeln!(out, "        ];").unwrap();
+        for input in &optional {
+            let name = arg_name(&input.name);
+            writeln!(out, "        if let Some({}) = {} {{", name, name).unwrap();
+            writeln!(out, "            params.push((\"{}\", {}));", input.name, to_score_value(&name, &input.param_type, false)).unwrap();
+            writeln!(out, "        }}").unwrap();
+        }
+        "&params"
+    };
+
+    if function.is_readonly() {
+        writeln!(out, "        let result = self.contract.call(\"{}\", {}).await?;", function.name, params).unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "        Ok(icon_sdk::contract::FromScoreValue::from_score_value(result)?)").unwrap();
+    } else {
+        let value = if function.is_payable() { "Some(icx_value)" } else { "None" };
+        writeln!(out, "        self.contract.send(wallet, \"{}\", {}, {}, version, nid, nonce, step_limit).await", function.name, params, value).unwrap();
+    }
+
+    writeln!(out, "    }}").unwrap();
+}
+
+fn write_event(out: &mut String, struct_name: &str, event: &ScoreApiEntry) {
+    let event_name = format!("{}Event", to_pascal_case(&event.name));
+
+    writeln!(out, "/// `{}` event of `{}`.", event.signature(), struct_name).unwrap();
+    writeln!(out, "#[derive(Clone, Debug, PartialEq)]").unwrap();
+    writeln!(out, "pub struct {} {{", event_name).unwrap();
+    for input in &event.inputs {
+        // Data values may be null, e.g. the `_data` of most IRC2 transfers
+        let field_type = if input.is_indexed() { owned_type(&input.param_type) } else { format!("Option<{}>", owned_type(&input.param_type)) };
+        writeln!(out, "    pub {}: {},", arg_name(&input.name), field_type).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "impl {} {{", event_name).unwrap();
+    writeln!(out, "    pub const SIGNATURE: &'static str = \"{}\";", event.signature()).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    pub fn from_event_log(log: &icon_sdk::responses::EventLog) -> Option<Self> {{").unwrap();
+    writeln!(out, "        if log.indexed.first()?.as_deref() != Some(Self::SIGNATURE) {{").unwrap();
+    writeln!(out, "            return None;").unwrap();
+    writeln!(out, "        }}").unwrap();
+    if event.inputs.iter().any(|input| input.is_indexed()) {
+        writeln!(out, "        let mut indexed = log.indexed.iter().skip(1);").unwrap();
+    }
+    if event.inputs.iter().any(|input| !input.is_indexed()) {
+        writeln!(out, "        let mut data = log.data.iter();").unwrap();
+    }
+    writeln!(out).unwrap();
+    writeln!(out, "        Some(Self {{").unwrap();
+    for input in &event.inputs {
+        let source = if input.is_indexed() { "indexed.next()?" } else { "data.next().unwrap_or(&None)" };
+        writeln!(out, "            {}: icon_sdk::contract::decode_event_arg({}, \"{}\")?,", arg_name(&input.name), source, input.param_type).unwrap();
+    }
+    writeln!(out, "        }})").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+fn arg_type(param_type: &str) -> String {
+    match param_type {
+        "str" | "Address" => "&str".to_string(),
+        "bytes" => "&[u8]".to_string(),
+        _ => owned_type(param_type),
+    }
+}
+
+fn owned_type(param_type: &str) -> String {
+    if let Some(item_type) = param_type.strip_prefix("[]") {
+        return format!("Vec<{}>", owned_type(item_type));
+    }
+
+    match param_type {
+        "int" => "icon_sdk::num_bigint::BigInt".to_string(),
+        "bool" => "bool".to_string(),
+        "str" | "Address" => "String".to_string(),
+        "bytes" => "Vec<u8>".to_string(),
+        _ => SCORE_VALUE.to_string(),
+    }
+}
+
+fn to_score_value(expr: &str, param_type: &str, owned: bool) -> String {
+    if let Some(item_type) = param_type.strip_prefix("[]") {
+        let convert = match item_type {
+            "Address" => format!("{}::Address", SCORE_VALUE),
+            "int" | "bool" | "str" | "bytes" => format!("{}::from", SCORE_VALUE),
+            _ if item_type.starts_with("[]") => format!("|item| {}", to_score_value("item", item_type, true)),
+            _ => return format!("{}::List({})", SCORE_VALUE, expr),
+        };
+        return format!("{}::List({}.into_iter().map({}).collect())", SCORE_VALUE, expr, convert);
+    }
+
+    match param_type {
+        "Address" if owned => format!("{}::Address({})", SCORE_VALUE, expr),
+        "Address" => format!("{}::address({})", SCORE_VALUE, expr),
+        "int" | "bool" | "str" | "bytes" => format!("{}::from({})", SCORE_VALUE, expr),
+        _ => expr.to_string(),
+    }
+}
+
+fn arg_name(name: &str) -> String {
+    let ident = to_ident(name);
+    if RESERVED_ARGS.contains(&ident.as_str()) {
+        format!("{}_arg", ident)
+    } else {
+        ident
+    }
+}
+
+fn to_ident(name: &str) -> String {
+    let snake = to_snake_case(name);
+    if snake.is_empty() {
+        "arg".to_string()
+    } else if snake.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", snake)
+    } else if KEYWORDS.contains(&snake.as_str()) {
+        format!("r#{}", snake)
+    } else if PATH_KEYWORDS.contains(&snake.as_str()) {
+        format!("{}_", snake)
+    } else {
+        snake
+    }
+}
+
+/// `balanceOf` -> `balance_of`, `_owner` -> `owner`, `ICXTransfer` -> `icx_transfer`.
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_ascii_lowercase());
+            if prev.is_ascii_lowercase() || prev.is_ascii_digit() || (prev.is_ascii_uppercase() && next_is_lower) {
+                snake.push('_');
+            }
+        }
+        if c.is_ascii_alphanumeric() {
+            snake.push(c.to_ascii_lowercase());
+        } else if !snake.is_empty() && !snake.ends_with('_') {
+            snake.push('_');
+        }
+    }
+
+    snake.trim_matches('_').to_string()
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
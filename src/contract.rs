@@ -0,0 +1,469 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use hex::{decode, encode};
+use num_bigint::BigInt;
+use num_traits::{Num, Signed};
+use serde_json::{json, Map, Value};
+use crate::error::IconError;
+use crate::icon_service::IconService;
+use crate::responses::{ScoreApi, ScoreApiEntry, ScoreApiParam};
+use crate::transaction_builder::TransactionBuilder;
+use crate::utils::transaction_types::TransactionType;
+use crate::wallet::Wallet;
+
+/// A value passed to or returned from a SCORE, converted to and from ICON's hex string encoding.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScoreValue {
+    Int(BigInt),
+    Bool(bool),
+    Str(String),
+    Bytes(Vec<u8>),
+    Address(String),
+    List(Vec<ScoreValue>),
+    Struct(BTreeMap<String, ScoreValue>),
+    Null,
+}
+
+impl ScoreValue {
+    pub fn address(address: &str) -> Self {
+        ScoreValue::Address(address.to_string())
+    }
+
+    pub fn as_int(&self) -> Option<&BigInt> {
+        match self {
+            ScoreValue::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ScoreValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ScoreValue::Str(value) | ScoreValue::Address(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            ScoreValue::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[ScoreValue]> {
+        match self {
+            ScoreValue::List(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<&BTreeMap<String, ScoreValue>> {
+        match self {
+            ScoreValue::Struct(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Encodes the value as the ABI type `param_type`, as expected by `icx_call` and `icx_sendTransaction`.
+    pub fn encode(&self, param_type: &str, fields: Option<&[ScoreApiParam]>) -> Result<Value, IconError> {
+        if let Some(item_type) = param_type.strip_prefix("[]") {
+            return match self {
+                ScoreValue::List(items) => items.iter()
+                    .map(|item| item.encode(item_type, fields))
+                    .collect::<Result<Vec<Value>, IconError>>()
+                    .map(Value::Array),
+                _ => Err(Self::mismatch(param_type, self)),
+            };
+        }
+
+        match (param_type, self) {
+            ("int", ScoreValue::Int(value)) => Ok(json!(encode_int(value))),
+            ("bool", ScoreValue::Bool(value)) => Ok(json!(if *value { "0x1" } else { "0x0" })),
+            ("str", ScoreValue::Str(value)) => Ok(json!(value)),
+            ("bytes", ScoreValue::Bytes(value)) => Ok(json!(format!("0x{}", encode(value)))),
+            ("Address", ScoreValue::Address(value)) | ("Address", ScoreValue::Str(value)) => {
+                if is_address(value) {
+                    Ok(json!(value))
+                } else {
+                    Err(IconError::InvalidCall(format!("invalid address: {}", value)))
+                }
+            },
+            ("struct", ScoreValue::Struct(values)) => {
+                let fields = fields.ok_or_else(|| IconError::InvalidCall("struct type without fields".to_string()))?;
+                let mut encoded = Map::new();
+                for field in fields {
+                    let value = values.get(&field.name)
+                        .ok_or_else(|| IconError::InvalidCall(format!("missing struct field: {}", field.name)))?;
+                    encoded.insert(field.name.clone(), value.encode(&field.param_type, field.fields.as_deref())?);
+                }
+                Ok(Value::Object(encoded))
+            },
+            _ => Err(Self::mismatch(param_type, self)),
+        }
+    }
+
+    /// Decodes a value returned by a SCORE, using the ABI type when it is known.
+    pub fn decode(value: &Value, param_type: &str, fields: Option<&[ScoreApiParam]>) -> Result<Self, IconError> {
+        if value.is_null() {
+            return Ok(ScoreValue::Null);
+        }
+
+        if let Some(item_type) = param_type.strip_prefix("[]") {
+            return match value {
+                Value::Array(items) => items.iter()
+                    .map(|item| Self::decode(item, item_type, fields))
+                    .collect::<Result<Vec<ScoreValue>, IconError>>()
+                    .map(ScoreValue::List),
+                _ => Err(IconError::InvalidCall(format!("expected a list, got {}", value))),
+            };
+        }
+
+        let invalid = || IconError::InvalidCall(format!("invalid {} value: {}", param_type, value));
+        match param_type {
+            "int" => value.as_str().and_then(decode_int).map(ScoreValue::Int).ok_or_else(invalid),
+            "bool" => match value.as_str() {
+                Some("0x1") => Ok(ScoreValue::Bool(true)),
+                Some("0x0") => Ok(ScoreValue::Bool(false)),
+                _ => Err(invalid()),
+            },
+            "str" => value.as_str().map(|value| ScoreValue::Str(value.to_string())).ok_or_else(invalid),
+            "bytes" => value.as_str()
+                .and_then(|value| decode(value.strip_prefix("0x").unwrap_or(value)).ok())
+                .map(ScoreValue::Bytes)
+                .ok_or_else(invalid),
+            "Address" => value.as_str().map(ScoreValue::address).ok_or_else(invalid),
+            "struct" if fields.is_some() => {
+                let object = value.as_object().ok_or_else(invalid)?;
+                let mut values = BTreeMap::new();
+                for field in fields.unwrap_or_default() {
+                    let field_value = object.get(&field.name).unwrap_or(&Value::Null);
+                    values.insert(field.name.clone(), Self::decode(field_value, &field.param_type, field.fields.as_deref())?);
+                }
+                Ok(ScoreValue::Struct(values))
+            },
+            _ => Ok(Self::from_json(value)),
+        }
+    }
+
+    // Values without a precise type (`list`, `dict`, ...) keep their JSON shape
+    fn from_json(value: &Value) -> Self {
+        match value {
+            Value::Null => ScoreValue::Null,
+            Value::Bool(value) => ScoreValue::Bool(*value),
+            Value::Number(value) => ScoreValue::Str(value.to_string()),
+            Value::String(value) => ScoreValue::Str(value.clone()),
+            Value::Array(items) => ScoreValue::List(items.iter().map(Self::from_json).collect()),
+            Value::Object(object) => ScoreValue::Struct(object.iter()
+                .map(|(key, value)| (key.clone(), Self::from_json(value)))
+                .collect()),
+        }
+    }
+
+    fn mismatch(param_type: &str, value: &ScoreValue) -> IconError {
+        IconError::InvalidCall(format!("expected {}, got {:?}", param_type, value))
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for ScoreValue {
+                fn from(value: $t) -> Self {
+                    ScoreValue::Int(BigInt::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl From<BigInt> for ScoreValue {
+    fn from(value: BigInt) -> Self {
+        ScoreValue::Int(value)
+    }
+}
+
+impl From<bool> for ScoreValue {
+    fn from(value: bool) -> Self {
+        ScoreValue::Bool(value)
+    }
+}
+
+impl From<&str> for ScoreValue {
+    fn from(value: &str) -> Self {
+        ScoreValue::Str(value.to_string())
+    }
+}
+
+impl From<String> for ScoreValue {
+    fn from(value: String) -> Self {
+        ScoreValue::Str(value)
+    }
+}
+
+impl From<Vec<u8>> for ScoreValue {
+    fn from(value: Vec<u8>) -> Self {
+        ScoreValue::Bytes(value)
+    }
+}
+
+impl From<&[u8]> for ScoreValue {
+    fn from(value: &[u8]) -> Self {
+        ScoreValue::Bytes(value.to_vec())
+    }
+}
+
+impl From<Vec<ScoreValue>> for ScoreValue {
+    fn from(value: Vec<ScoreValue>) -> Self {
+        ScoreValue::List(value)
+    }
+}
+
+impl From<BTreeMap<String, ScoreValue>> for ScoreValue {
+    fn from(value: BTreeMap<String, ScoreValue>) -> Self {
+        ScoreValue::Struct(value)
+    }
+}
+
+/// Converts a decoded `ScoreValue` into a Rust type, used by generated bindings.
+pub trait FromScoreValue: Sized {
+    fn from_score_value(value: ScoreValue) -> Result<Self, IconError>;
+}
+
+impl FromScoreValue for ScoreValue {
+    fn from_score_value(value: ScoreValue) -> Result<Self, IconError> {
+        Ok(value)
+    }
+}
+
+impl FromScoreValue for BigInt {
+    fn from_score_value(value: ScoreValue) -> Result<Self, IconError> {
+        match value {
+            ScoreValue::Int(value) => Ok(value),
+            value => Err(ScoreValue::mismatch("int", &value)),
+        }
+    }
+}
+
+impl FromScoreValue for bool {
+    fn from_score_value(value: ScoreValue) -> Result<Self, IconError> {
+        match value {
+            ScoreValue::Bool(value) => Ok(value),
+            value => Err(ScoreValue::mismatch("bool", &value)),
+        }
+    }
+}
+
+impl FromScoreValue for String {
+    fn from_score_value(value: ScoreValue) -> Result<Self, IconError> {
+        match value {
+            ScoreValue::Str(value) | ScoreValue::Address(value) => Ok(value),
+            value => Err(ScoreValue::mismatch("str", &value)),
+        }
+    }
+}
+
+impl FromScoreValue for Vec<u8> {
+    fn from_score_value(value: ScoreValue) -> Result<Self, IconError> {
+        match value {
+            ScoreValue::Bytes(value) => Ok(value),
+            value => Err(ScoreValue::mismatch("bytes", &value)),
+        }
+    }
+}
+
+impl<T: FromScoreValue> FromScoreValue for Vec<T> {
+    fn from_score_value(value: ScoreValue) -> Result<Self, IconError> {
+        match value {
+            ScoreValue::List(items) => items.into_iter().map(T::from_score_value).collect(),
+            value => Err(ScoreValue::mismatch("list", &value)),
+        }
+    }
+}
+
+impl<T: FromScoreValue> FromScoreValue for Option<T> {
+    fn from_score_value(value: ScoreValue) -> Result<Self, IconError> {
+        match value {
+            ScoreValue::Null => Ok(None),
+            value => T::from_score_value(value).map(Some),
+        }
+    }
+}
+
+/// Decodes a single indexed or data value of an event log, used by generated bindings.
+pub fn decode_event_arg<T: FromScoreValue>(value: &Option<String>, param_type: &str) -> Option<T> {
+    let value = match value {
+        Some(value) => Value::String(value.clone()),
+        None => Value::Null,
+    };
+
+    ScoreValue::decode(&value, param_type, None)
+        .and_then(T::from_score_value)
+        .ok()
+}
+
+pub(crate) fn encode_int(value: &BigInt) -> String {
+    if value.is_negative() {
+        format!("-0x{}", (-value).to_str_radix(16))
+    } else {
+        format!("0x{}", value.to_str_radix(16))
+    }
+}
+
+pub(crate) fn decode_int(value: &str) -> Option<BigInt> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value),
+    };
+    let value = BigInt::from_str_radix(value.strip_prefix("0x")?, 16).ok()?;
+
+    Some(if negative { -value } else { value })
+}
+
+pub(crate) fn is_address(value: &str) -> bool {
+    (value.starts_with("hx") || value.starts_with("cx"))
+        && value.len() == 42
+        && value[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// A client for any SCORE, driven by its ABI.
+///
+/// Method names and parameters are checked against the ABI before anything is sent,
+/// and readonly call results are decoded according to the declared output type.
+pub struct Contract {
+    icon_service: IconService,
+    contract_address: String,
+    score_api: ScoreApi,
+}
+
+impl Contract {
+    /// Fetches the ABI of `contract_address` with `icx_getScoreApi`.
+    pub async fn new(icon_service: IconService, contract_address: &str) -> Result<Self, Box<dyn Error>> {
+        let score_api = icon_service.get_score_api(contract_address).await?;
+
+        Ok(Self::with_api(icon_service, contract_address, score_api))
+    }
+
+    /// Uses an ABI that was fetched earlier, e.g. loaded from a file.
+    pub fn with_api(icon_service: IconService, contract_address: &str, score_api: ScoreApi) -> Self {
+        Self {
+            icon_service,
+            contract_address: contract_address.to_string(),
+            score_api,
+        }
+    }
+
+    pub fn address(&self) -> &str {
+        &self.contract_address
+    }
+
+    pub fn score_api(&self) -> &ScoreApi {
+        &self.score_api
+    }
+
+    /// Validates the parameters against the ABI and builds the `data` of the call.
+    pub fn encode_call(&self, method: &str, params: &[(&str, ScoreValue)]) -> Result<Value, IconError> {
+        let function = self.function(method)?;
+
+        for (name, _) in params {
+            if !function.inputs.iter().any(|input| input.name == *name) {
+                return Err(IconError::InvalidCall(format!("unknown parameter {} for {}", name, method)));
+            }
+        }
+
+        let mut encoded = Map::new();
+        for input in &function.inputs {
+            match params.iter().find(|(name, _)| *name == input.name) {
+                // Only optional inputs may be passed as null
+                Some((_, ScoreValue::Null)) if input.is_optional() => {
+                    encoded.insert(input.name.clone(), Value::Null);
+                },
+                Some((_, value)) => {
+                    encoded.insert(input.name.clone(), value.encode(&input.param_type, input.fields.as_deref())?);
+                },
+                None if input.is_optional() => {},
+                None => return Err(IconError::InvalidCall(format!("missing parameter {} for {}", input.name, method))),
+            }
+        }
+
+        if encoded.is_empty() {
+            Ok(json!({ "method": method }))
+        } else {
+            Ok(json!({ "method": method, "params": encoded }))
+        }
+    }
+
+    /// Decodes the result of a readonly call according to the method's output type.
+    pub fn decode_output(&self, method: &str, value: &Value) -> Result<ScoreValue, IconError> {
+        let function = self.function(method)?;
+
+        match function.outputs.first() {
+            Some(output) => ScoreValue::decode(value, &output.param_type, output.fields.as_deref()),
+            None => Ok(ScoreValue::from_json(value)),
+        }
+    }
+
+    /// Calls a readonly method and decodes its result.
+    pub async fn call(&self, method: &str, params: &[(&str, ScoreValue)]) -> Result<ScoreValue, Box<dyn Error>> {
+        if !self.function(method)?.is_readonly() {
+            return Err(Box::new(IconError::InvalidCall(format!("{} is not readonly", method))));
+        }
+
+        let response = self.icon_service.call(&self.contract_address, self.encode_call(method, params)?).await?;
+        if let Some(error) = response.get("error") {
+            return Err(Box::new(IconError::Rpc {
+                code: error["code"].as_i64().unwrap_or_default(),
+                message: error["message"].as_str().unwrap_or_default().to_string(),
+            }));
+        }
+
+        Ok(self.decode_output(method, &response["result"])?)
+    }
+
+    /// Signs and sends a transaction invoking a writable method. `value` is only allowed for payable methods.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn send(&self, wallet: &Wallet, method: &str, params: &[(&str, ScoreValue)], value: Option<&str>, version: &str, nid: Option<&str>, nonce: &str, step_limit: &str) -> Result<Value, Box<dyn Error>> {
+        let function = self.function(method)?;
+        if function.is_readonly() {
+            return Err(Box::new(IconError::InvalidCall(format!("{} is readonly", method))));
+        }
+        if value.is_some() && !function.is_payable() {
+            return Err(Box::new(IconError::InvalidCall(format!("{} is not payable", method))));
+        }
+
+        let mut builder = TransactionBuilder::new(&self.icon_service)
+            .method(TransactionType::SendTransaction.as_str())
+            .from(wallet.get_public_address().as_str())
+            .to(&self.contract_address)
+            .version(version)
+            .nid_or_default(nid)
+            .timestamp()
+            .nonce(nonce)
+            .step_limit(step_limit)
+            .call(self.encode_call(method, params)?);
+        if let Some(value) = value {
+            builder = builder.value(value);
+        }
+
+        let transaction = builder
+            .sign(wallet.get_private_key().as_str())
+            .build()?;
+
+        let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        Ok(response)
+    }
+
+    fn function(&self, method: &str) -> Result<&ScoreApiEntry, IconError> {
+        self.score_api.function(method)
+            .ok_or_else(|| IconError::InvalidCall(format!("unknown method: {}", method)))
+    }
+}
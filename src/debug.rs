@@ -0,0 +1,84 @@
+use std::error::Error;
+use num_bigint::BigInt;
+use crate::contract::decode_int;
+use crate::error::IconError;
+use crate::events::ICXTransfer;
+use crate::icon_service::IconService;
+use crate::requests::{BlockId, EstimateStep, GetDebugAccount, GetTrace};
+use crate::responses::{DebugAccount, Trace};
+use crate::transaction::Transaction;
+
+/// The `debug_*` methods of a node, served on their own endpoint (`/api/v3d` by default).
+///
+/// Only nodes started with the debug API enabled serve them. Get one with `IconService::debug`
+/// or point it at any URL with `DebugClient::new`.
+///
+/// goloop has no debug method for SCORE APIs: read the API of a SCORE at a past block with
+/// `IconService::get_score_api_at`, and its code and storage with `get_account`.
+#[derive(Clone)]
+pub struct DebugClient {
+    icon_service: IconService,
+}
+
+impl DebugClient {
+    pub fn new(debug_url: &str) -> Self {
+        Self {
+            icon_service: IconService::new(Some(debug_url.to_string())),
+        }
+    }
+
+    pub(crate) fn for_service(icon_service: &IconService) -> Self {
+        Self {
+            icon_service: IconService { icon_service_url: icon_service.debug_url(), ..icon_service.clone() },
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.icon_service.icon_service_url
+    }
+
+    /// The execution logs of the transaction `tx_hash`.
+    pub async fn get_trace(&self, tx_hash: &str) -> Result<Trace, Box<dyn Error>> {
+        self.icon_service.request(&GetTrace { tx_hash: tx_hash.to_string() }).await
+    }
+
+    /// The ICX sent by SCOREs while executing the transaction `tx_hash`, read from its trace.
+    /// Works for blocks that predate `ICXTransfer` event logs.
+    pub async fn get_internal_transfers(&self, tx_hash: &str) -> Result<Vec<ICXTransfer>, Box<dyn Error>> {
+        Ok(ICXTransfer::from_trace(&self.get_trace(tx_hash).await?))
+    }
+
+    /// The account at `address` in the state of the block `at`, limited to the parts selected
+    /// by the `filter` bitmask if given.
+    pub async fn get_account(&self, address: &str, at: BlockId, filter: Option<u32>) -> Result<DebugAccount, Box<dyn Error>> {
+        self.icon_service.request(&GetDebugAccount {
+            address: address.to_string(),
+            height: at.height(),
+            filter: filter.map(|filter| format!("0x{:x}", filter)),
+        }).await
+    }
+
+    /// The steps `transaction` would use, to pick its step limit. The transaction is not signed
+    /// and any `stepLimit` or `signature` it has is left out.
+    pub async fn estimate_step(&self, transaction: &Transaction) -> Result<BigInt, Box<dyn Error>> {
+        let mut params = transaction.data["params"].as_object().cloned().unwrap_or_default();
+        params.remove("stepLimit");
+        params.remove("signature");
+
+        let steps = self.icon_service.fetch(&EstimateStep { params }).await?;
+
+        Ok(decode_int(&steps).ok_or_else(|| IconError::UnexpectedResponse(format!("invalid step estimate: {}", steps)))?)
+    }
+}
+
+/// Turns the JSON-RPC URL into the URL of the debug API, e.g.
+/// `https://api.icon.community/api/v3/icon_dex` -> `https://api.icon.community/api/v3d/icon_dex`.
+pub(crate) fn debug_url(icon_service_url: &str) -> String {
+    let url = icon_service_url.trim_end_matches('/');
+    match url.rfind("/api/v3") {
+        Some(index) if url[index + 7..].is_empty() || url[index + 7..].starts_with('/') => {
+            format!("{}/api/v3d{}", &url[..index], &url[index + 7..])
+        },
+        _ => url.to_string(),
+    }
+}
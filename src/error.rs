@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum IconError {
+    #[error("request failed")]
+    Request(#[from] reqwest::Error),
+
+    #[error("unexpected response: {0}")]
+    UnexpectedResponse(String),
+
+    #[error("rpc error {code}: {message}")]
+    Rpc { code: i64, message: String },
+
+    #[error("failed to decode response")]
+    Decode(#[from] serde_json::Error),
+
+    #[error("invalid transaction: {}", .0.join(", "))]
+    InvalidTransaction(Vec<String>),
+
+    #[error("invalid call: {0}")]
+    InvalidCall(String),
+
+    #[error("invalid event: {0}")]
+    InvalidEvent(String),
+
+    #[error("block {height} does not extend the previous block")]
+    ChainMismatch { height: u64 },
+
+    #[error("invalid rlp: {0}")]
+    Rlp(String),
+
+    #[error("invalid private key")]
+    InvalidPrivateKey,
+
+    #[error("verification failed: {0}")]
+    Verification(String),
+
+    #[error("i/o error")]
+    Io(#[from] std::io::Error),
+}
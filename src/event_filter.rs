@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use crate::error::IconError;
+use crate::events::{EventArg, EventSignature};
+use crate::logs_bloom::{event_arg_to_bytes, event_bloom, LogsBloom};
+use crate::responses::EventLog;
+
+/// Selects event logs by SCORE address, signature and argument values.
+///
+/// Uses the same format as goloop's websocket event filter: `indexed` and `data` list the
+/// expected values per position, `None` matching anything. Values are compared in their binary
+/// form, so `0x0a` matches `0xa` for an `int` argument.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventFilter {
+    #[serde(rename = "addr", default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(rename = "event")]
+    pub signature: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indexed: Vec<Option<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub data: Vec<Option<String>>,
+}
+
+impl EventFilter {
+    pub fn new(signature: &str) -> Self {
+        Self {
+            signature: signature.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn address(mut self, address: &str) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn indexed(mut self, indexed: Vec<Option<&str>>) -> Self {
+        self.indexed = indexed.into_iter().map(|value| value.map(str::to_string)).collect();
+        self
+    }
+
+    pub fn data(mut self, data: Vec<Option<&str>>) -> Self {
+        self.data = data.into_iter().map(|value| value.map(str::to_string)).collect();
+        self
+    }
+
+    /// Checks the signature and that every expected value fits its argument type.
+    pub fn validate(&self) -> Result<(), IconError> {
+        let parsed = EventSignature::parse(&self.signature)?;
+        if self.indexed.len() + self.data.len() > parsed.types.len() {
+            return Err(IconError::InvalidEvent(format!("{} has only {} arguments", self.signature, parsed.types.len())));
+        }
+
+        // Like goloop, data values follow the indexed ones listed in the filter
+        for (value, arg_type) in self.indexed.iter().chain(self.data.iter()).zip(parsed.types.iter()) {
+            if let Some(value) = value {
+                event_arg_to_bytes(&EventArg::decode(Some(value), arg_type)?)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn matches(&self, log: &EventLog) -> bool {
+        if self.address.as_ref().is_some_and(|address| *address != log.score_address) {
+            return false;
+        }
+        if log.indexed.first().and_then(|signature| signature.as_deref()) != Some(self.signature.as_str()) {
+            return false;
+        }
+        let types = match EventSignature::parse(&self.signature) {
+            Ok(parsed) => parsed.types,
+            Err(_) => return false,
+        };
+
+        let indexed_count = log.indexed.len() - 1;
+        let indexed = self.indexed.iter().enumerate()
+            .all(|(i, expected)| arg_matches(expected, log.indexed.get(i + 1), types.get(i)));
+        let data = self.data.iter().enumerate()
+            .all(|(i, expected)| arg_matches(expected, log.data.get(i), types.get(indexed_count + i)));
+
+        indexed && data
+    }
+
+    /// Indexes of the matching logs, as listed in the `events` of a websocket notification.
+    pub fn matching_events(&self, logs: &[EventLog]) -> Vec<usize> {
+        logs.iter()
+            .enumerate()
+            .filter(|(_, log)| self.matches(log))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// The bits a matching log sets in a logs bloom, to skip blocks and receipts that can't match.
+    pub fn bloom(&self) -> Result<LogsBloom, IconError> {
+        let indexed: Vec<Option<&str>> = self.indexed.iter().map(|value| value.as_deref()).collect();
+
+        event_bloom(self.address.as_deref(), &self.signature, &indexed)
+    }
+}
+
+fn arg_matches(expected: &Option<String>, actual: Option<&Option<String>>, arg_type: Option<&String>) -> bool {
+    let expected = match expected {
+        Some(expected) => expected,
+        None => return true,
+    };
+    let (actual, arg_type) = match (actual, arg_type) {
+        (Some(Some(actual)), Some(arg_type)) => (actual, arg_type),
+        _ => return false,
+    };
+
+    match (arg_bytes(expected, arg_type), arg_bytes(actual, arg_type)) {
+        (Some(expected), Some(actual)) => expected == actual,
+        _ => false,
+    }
+}
+
+fn arg_bytes(value: &str, arg_type: &str) -> Option<Vec<u8>> {
+    event_arg_to_bytes(&EventArg::decode(Some(value), arg_type).ok()?).ok()
+}
@@ -0,0 +1,281 @@
+use num_bigint::BigInt;
+use serde_json::Value;
+use crate::contract::{decode_int, ScoreValue};
+use crate::error::IconError;
+use crate::responses::{EventLog, Trace, TransactionResult};
+
+/// A decoded event log argument.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventArg {
+    Address(String),
+    Int(BigInt),
+    Bool(bool),
+    Bytes(Vec<u8>),
+    Str(String),
+    Null,
+}
+
+impl EventArg {
+    pub fn as_address(&self) -> Option<&str> {
+        match self {
+            EventArg::Address(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<&BigInt> {
+        match self {
+            EventArg::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            EventArg::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            EventArg::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            EventArg::Str(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Decodes a raw indexed or data value according to its type in the event signature.
+    pub fn decode(value: Option<&str>, arg_type: &str) -> Result<Self, IconError> {
+        let value = match value {
+            Some(value) => Value::String(value.to_string()),
+            None => return Ok(EventArg::Null),
+        };
+
+        match ScoreValue::decode(&value, arg_type, None)? {
+            ScoreValue::Address(value) => Ok(EventArg::Address(value)),
+            ScoreValue::Int(value) => Ok(EventArg::Int(value)),
+            ScoreValue::Bool(value) => Ok(EventArg::Bool(value)),
+            ScoreValue::Bytes(value) => Ok(EventArg::Bytes(value)),
+            ScoreValue::Str(value) => Ok(EventArg::Str(value)),
+            _ => Err(IconError::InvalidEvent(format!("unsupported event argument type: {}", arg_type))),
+        }
+    }
+}
+
+/// A parsed event signature such as `Transfer(Address,Address,int,bytes)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventSignature {
+    pub name: String,
+    pub types: Vec<String>,
+}
+
+impl EventSignature {
+    pub fn parse(signature: &str) -> Result<Self, IconError> {
+        let invalid = || IconError::InvalidEvent(format!("invalid event signature: {}", signature));
+
+        let (name, rest) = signature.split_once('(').ok_or_else(invalid)?;
+        let types = rest.strip_suffix(')').ok_or_else(invalid)?;
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        let types = if types.is_empty() {
+            Vec::new()
+        } else {
+            types.split(',').map(|arg_type| arg_type.trim().to_string()).collect()
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            types,
+        })
+    }
+}
+
+/// An event log with its arguments decoded according to its signature.
+///
+/// `args` follow the signature order; the first `indexed_count` of them were indexed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedEvent {
+    pub score_address: String,
+    pub signature: String,
+    pub name: String,
+    pub args: Vec<EventArg>,
+    pub indexed_count: usize,
+}
+
+impl DecodedEvent {
+    pub fn indexed(&self) -> &[EventArg] {
+        &self.args[..self.indexed_count]
+    }
+
+    pub fn data(&self) -> &[EventArg] {
+        &self.args[self.indexed_count..]
+    }
+}
+
+pub fn decode_event_log(log: &EventLog) -> Result<DecodedEvent, IconError> {
+    let signature = log.indexed.first()
+        .and_then(|signature| signature.as_deref())
+        .ok_or_else(|| IconError::InvalidEvent("missing event signature".to_string()))?;
+    let parsed = EventSignature::parse(signature)?;
+
+    // Indexed arguments always come first, the remaining ones are in `data`
+    let values: Vec<Option<&str>> = log.indexed[1..].iter()
+        .chain(log.data.iter())
+        .map(|value| value.as_deref())
+        .collect();
+    if values.len() != parsed.types.len() {
+        return Err(IconError::InvalidEvent(format!("{} expects {} arguments, got {}", signature, parsed.types.len(), values.len())));
+    }
+
+    let args = values.into_iter()
+        .zip(parsed.types.iter())
+        .map(|(value, arg_type)| EventArg::decode(value, arg_type))
+        .collect::<Result<Vec<EventArg>, IconError>>()?;
+
+    Ok(DecodedEvent {
+        score_address: log.score_address.clone(),
+        signature: signature.to_string(),
+        name: parsed.name,
+        args,
+        indexed_count: log.indexed.len() - 1,
+    })
+}
+
+/// IRC2 `Transfer(Address,Address,int,bytes)`, emitted by the token contract.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IRC2Transfer {
+    pub token: String,
+    pub from: String,
+    pub to: String,
+    pub value: BigInt,
+    pub data: Vec<u8>,
+}
+
+impl IRC2Transfer {
+    pub const SIGNATURE: &'static str = "Transfer(Address,Address,int,bytes)";
+
+    pub fn from_event_log(log: &EventLog) -> Option<Self> {
+        let event = decode_event_log(log).ok()?;
+        if event.signature != Self::SIGNATURE {
+            return None;
+        }
+
+        Some(Self {
+            token: event.score_address,
+            from: event.args[0].as_address()?.to_string(),
+            to: event.args[1].as_address()?.to_string(),
+            value: event.args[2].as_int()?.clone(),
+            data: event.args[3].as_bytes().map(|data| data.to_vec()).unwrap_or_default(),
+        })
+    }
+}
+
+/// `ICXTransfer(Address,Address,int)`, emitted when a SCORE sends ICX.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ICXTransfer {
+    pub from: String,
+    pub to: String,
+    pub amount: BigInt,
+}
+
+impl ICXTransfer {
+    pub const SIGNATURE: &'static str = "ICXTransfer(Address,Address,int)";
+
+    pub fn from_event_log(log: &EventLog) -> Option<Self> {
+        let event = decode_event_log(log).ok()?;
+        if event.signature != Self::SIGNATURE {
+            return None;
+        }
+
+        Some(Self {
+            from: event.args[0].as_address()?.to_string(),
+            to: event.args[1].as_address()?.to_string(),
+            amount: event.args[2].as_int()?.clone(),
+        })
+    }
+
+    /// The ICX sent by SCOREs while executing the transaction of `result`, in order.
+    ///
+    /// Only logs emitted by the sending SCORE itself are counted, so a SCORE can't fake
+    /// transfers from another one. Failed transactions have no transfers.
+    pub fn from_transaction_result(result: &TransactionResult) -> Vec<Self> {
+        if !result.is_success() {
+            return Vec::new();
+        }
+
+        result.event_logs.iter()
+            .filter_map(|log| Self::from_event_log(log).filter(|transfer| transfer.from == log.score_address))
+            .collect()
+    }
+
+    /// The ICX sent by SCOREs according to the trace of a transaction, see `DebugClient::get_trace`.
+    ///
+    /// For blocks that predate `ICXTransfer` event logs. Transfers made in calls that failed,
+    /// or by a transaction that failed, are left out.
+    pub fn from_trace(trace: &Trace) -> Vec<Self> {
+        if trace.status.as_deref().is_some_and(|status| status != "0x1") {
+            return Vec::new();
+        }
+
+        // Transfers made by each call in progress, moved to its caller once it succeeds
+        let mut frames: Vec<Vec<Self>> = vec![Vec::new()];
+        for log in &trace.logs {
+            // Messages logged inside a call frame start with its `FRAME[n]` prefix
+            let mut words = log.message.split_whitespace().peekable();
+            words.next_if(|word| word.starts_with("FRAME["));
+            match (words.next(), words.next()) {
+                (Some("CALL" | "TRANSFER"), Some("start")) => {
+                    let fields: Vec<(&str, &str)> = words.filter_map(|word| word.split_once('=')).collect();
+                    let field = |name: &str| fields.iter().find(|(key, _)| *key == name).map(|(_, value)| *value);
+                    let mut transfers = Vec::new();
+                    if let (Some(from), Some(to), Some(amount)) = (field("from"), field("to"), field("value").and_then(parse_amount)) {
+                        if from.starts_with("cx") && amount > BigInt::from(0) {
+                            transfers.push(Self { from: from.to_string(), to: to.to_string(), amount });
+                        }
+                    }
+                    frames.push(transfers);
+                },
+                (Some("CALL" | "TRANSFER"), Some("done")) if frames.len() > 1 => {
+                    let transfers = frames.pop().unwrap_or_default();
+                    let succeeded = words.filter_map(|word| word.strip_prefix("status="))
+                        .next()
+                        .is_none_or(|status| matches!(status, "Success" | "0" | "0x0"));
+                    if let Some(frame) = frames.last_mut().filter(|_| succeeded) {
+                        frame.extend(transfers);
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        frames.into_iter().flatten().collect()
+    }
+}
+
+// Amounts are logged in hex or decimal depending on the node
+fn parse_amount(value: &str) -> Option<BigInt> {
+    decode_int(value).or_else(|| value.parse().ok())
+}
+
+/// Events with a well-known meaning across SCOREs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StandardEvent {
+    IRC2Transfer(IRC2Transfer),
+    ICXTransfer(ICXTransfer),
+}
+
+impl StandardEvent {
+    pub fn from_event_log(log: &EventLog) -> Option<Self> {
+        IRC2Transfer::from_event_log(log).map(StandardEvent::IRC2Transfer)
+            .or_else(|| ICXTransfer::from_event_log(log).map(StandardEvent::ICXTransfer))
+    }
+}
@@ -1,27 +1,119 @@
 use std::error::Error;
+use std::time::Duration;
+use hex::decode;
+use num_bigint::BigInt;
+use sha3::{Digest, Sha3_256};
 use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
 use serde_json::{Value};
+use crate::btp::{BtpHeader, BtpNotification};
+use crate::utils::helpers::decode_base64;
+use crate::block_header::{decode_validators, BlockHeader, CommitVotes};
+use crate::block_stream::{self, BlockCursor};
+use crate::contract::decode_int;
+use crate::debug::{self, DebugClient};
+use crate::error::IconError;
+use futures_util::stream::BoxStream;
+use crate::event_filter::EventFilter;
+use crate::events::ICXTransfer;
+use crate::proof::decode_proof;
+use crate::requests::{GetBlockHeaderByHeight, GetDataByHash, GetNetworkInfo, GetTotalSupply, GetBtpHeader, GetBtpMessages, GetBtpNetworkInfo, GetBtpNetworkTypeInfo, GetBtpProof, GetBtpSourceInformation, GetProofForEvents, GetProofForResult, GetScoreApi, GetScoreStatus, GetTransactionResult, GetVotesByHeight, BlockId, Request};
+use crate::responses::{Block, BtpNetworkInfo, BtpNetworkTypeInfo, BtpSourceInformation, NetworkInfo, ScoreApi, ScoreStatus, Trace};
+use crate::transaction::SignedTransaction;
 use crate::transaction_builder::TransactionBuilder;
 use crate::utils::transaction_types::TransactionType;
 use crate::wallet::Wallet;
+use crate::websocket::{self, BlockNotification, EventMessage};
 
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct IconService {
     pub(crate) icon_service_url: String,
+    // Network id filled into transactions built from this service that don't set one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) nid: Option<String>,
+    // URL of the debug API, derived from `icon_service_url` when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) debug_url: Option<String>,
 }
 
 impl IconService {
     pub fn new(icon_service_url: Option<String>) -> Self {
         Self {
             icon_service_url: icon_service_url
-                .unwrap_or_else(|| "https://api.icon.community/api/v3".to_string())
+                .unwrap_or_else(|| "https://api.icon.community/api/v3".to_string()),
+            nid: None,
+            debug_url: None,
         }
     }
 
+    /// Sets the URL of the node's debug API, by default the JSON-RPC URL with `/api/v3d` for `/api/v3`.
+    pub fn with_debug_url(mut self, debug_url: &str) -> Self {
+        self.debug_url = Some(debug_url.to_string());
+        self
+    }
+
+    pub fn debug_url(&self) -> String {
+        self.debug_url.clone().unwrap_or_else(|| debug::debug_url(&self.icon_service_url))
+    }
+
+    /// The `debug_*` methods of the node, sent to `debug_url`.
+    pub fn debug(&self) -> DebugClient {
+        DebugClient::for_service(self)
+    }
+
+    /// Sets the network id used by transactions that don't set one.
+    pub fn with_nid(mut self, nid: &str) -> Self {
+        self.nid = Some(nid.to_string());
+        self
+    }
+
+    /// Asks the node for its network id with `get_network_info` and uses it for
+    /// transactions that don't set one.
+    pub async fn discover_nid(mut self) -> Result<Self, Box<dyn Error>> {
+        self.nid = Some(self.get_network_info().await?.nid);
+        Ok(self)
+    }
+
+    /// The network id used by transactions that don't set one, if known.
+    pub fn nid(&self) -> Option<&str> {
+        self.nid.as_deref()
+    }
+
+    /// Sends a typed request and decodes the `result` of the response.
+    /// JSON-RPC errors returned by the node are reported as `IconError::Rpc`.
+    pub async fn request<R: Request>(&self, request: &R) -> Result<R::Response, Box<dyn Error>> {
+        Ok(self.fetch(request).await?)
+    }
+
+    pub(crate) async fn fetch<R: Request>(&self, request: &R) -> Result<R::Response, IconError> {
+        let mut builder = TransactionBuilder::new(self)
+            .method(request.method().as_str());
+
+        let params = request.params();
+        if !params.is_empty() {
+            builder = builder.set_params(&params);
+        }
+
+        let response: Value = builder.build()?.send().await?;
+
+        Self::parse_result(response)
+    }
+
+    fn parse_result<T: DeserializeOwned>(response: Value) -> Result<T, IconError> {
+        if let Some(error) = response.get("error") {
+            return Err(IconError::Rpc {
+                code: error["code"].as_i64().unwrap_or_default(),
+                message: error["message"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+
+        Ok(serde_json::from_value(response["result"].clone())?)
+    }
+
     pub async fn get_last_block(&self) -> Result<Value, Box<dyn Error>> {
         let transaction = TransactionBuilder::new(self)
             .method(TransactionType::LastBlock.as_str())
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
@@ -32,7 +124,7 @@ impl IconService {
         let transaction = TransactionBuilder::new(self)
             .method(TransactionType::BlockByHeight.as_str())
             .block_height(block_height)
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
@@ -43,18 +135,90 @@ impl IconService {
         let transaction = TransactionBuilder::new(self)
             .method(TransactionType::BlockByHash.as_str())
             .block_hash(block_hash)
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
         Ok(response)
     }
 
+    pub async fn get_block_header_by_height(&self, block_height: u64) -> Result<BlockHeader, Box<dyn Error>> {
+        let header = self.fetch(&GetBlockHeaderByHeight { height: format!("0x{:x}", block_height) }).await?;
+
+        Ok(BlockHeader::from_base64(&header)?)
+    }
+
+    /// The votes committing the block at `block_height`, to check with `CommitVotes::verify`.
+    pub async fn get_votes_by_height(&self, block_height: u64) -> Result<CommitVotes, Box<dyn Error>> {
+        let votes = self.fetch(&GetVotesByHeight { height: format!("0x{:x}", block_height) }).await?;
+
+        Ok(CommitVotes::from_base64(&votes)?)
+    }
+
+    /// The data stored under `hash`, checked to hash to it.
+    pub async fn get_data_by_hash(&self, hash: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        let data = decode_base64(&self.fetch(&GetDataByHash { hash: hash.to_string() }).await?)?;
+
+        let expected = decode(hash.strip_prefix("0x").unwrap_or(hash)).map_err(|_| IconError::InvalidCall(format!("invalid hash: {}", hash)))?;
+        if Sha3_256::digest(&data).as_slice() != expected.as_slice() {
+            return Err(Box::new(IconError::Verification(format!("data does not match hash {}", hash))));
+        }
+
+        Ok(data)
+    }
+
+    /// The validator list stored under `hash`, e.g. the `next_validators_hash` of a block header.
+    pub async fn get_validators_by_hash(&self, hash: &str) -> Result<Vec<String>, Box<dyn Error>> {
+        Ok(decode_validators(&self.get_data_by_hash(hash).await?)?)
+    }
+
+    /// The commit votes stored under `hash`, e.g. the `votes_hash` of a block header.
+    pub async fn get_votes_by_hash(&self, hash: &str) -> Result<CommitVotes, Box<dyn Error>> {
+        Ok(CommitVotes::from_bytes(&self.get_data_by_hash(hash).await?)?)
+    }
+
+    /// The proof of the receipt at `index` in the block `block_hash`, to check with `proof::verify_result_proof`.
+    pub async fn get_proof_for_result(&self, block_hash: &str, index: &str) -> Result<Vec<Vec<u8>>, Box<dyn Error>> {
+        let proof = self.fetch(&GetProofForResult { hash: block_hash.to_string(), index: index.to_string() }).await?;
+
+        Ok(decode_proof(&proof)?)
+    }
+
+    /// The proof of the receipt at `index`, followed by the proof of each of its event logs listed in `events`.
+    pub async fn get_proof_for_events(&self, block_hash: &str, index: &str, events: &[&str]) -> Result<Vec<Vec<Vec<u8>>>, Box<dyn Error>> {
+        let proofs = self.fetch(&GetProofForEvents {
+            hash: block_hash.to_string(),
+            index: index.to_string(),
+            events: events.iter().map(|event| event.to_string()).collect(),
+        }).await?;
+
+        Ok(proofs.iter().map(|proof| decode_proof(proof)).collect::<Result<Vec<_>, IconError>>()?)
+    }
+
+    /// The total supply of ICX, in loop.
+    pub async fn get_total_supply(&self) -> Result<BigInt, Box<dyn Error>> {
+        let total_supply = self.fetch(&GetTotalSupply).await?;
+
+        Ok(decode_int(&total_supply).ok_or_else(|| IconError::UnexpectedResponse(format!("invalid total supply: {}", total_supply)))?)
+    }
+
+    pub async fn get_network_info(&self) -> Result<NetworkInfo, Box<dyn Error>> {
+        self.request(&GetNetworkInfo).await
+    }
+
     pub async fn get_balance(&self, address: &str) -> Result<Value, Box<dyn Error>> {
-        let transaction = TransactionBuilder::new(self)
+        self.get_balance_at(address, BlockId::Latest).await
+    }
+
+    /// The balance of `address` at the block `at`, e.g. `BlockId::Height(snapshot)`.
+    pub async fn get_balance_at(&self, address: &str, at: BlockId) -> Result<Value, Box<dyn Error>> {
+        let mut builder = TransactionBuilder::new(self)
             .method(TransactionType::Balance.as_str())
-            .address(address)
-            .build();
+            .address(address);
+        if let Some(height) = at.height() {
+            builder = builder.block_height(&height);
+        }
+        let transaction = builder.build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
@@ -65,72 +229,185 @@ impl IconService {
         let transaction = TransactionBuilder::new(self)
             .method(TransactionType::TransactionResult.as_str())
             .tx_hash(tx_hash)
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
         Ok(response)
     }
 
+    /// The ICX sent by SCOREs while executing the transaction `tx_hash`, read from its
+    /// `ICXTransfer` event logs. See `DebugClient::get_internal_transfers` for older blocks.
+    pub async fn get_internal_transfers(&self, tx_hash: &str) -> Result<Vec<ICXTransfer>, Box<dyn Error>> {
+        let result = self.fetch(&GetTransactionResult { tx_hash: tx_hash.to_string() }).await?;
+
+        Ok(ICXTransfer::from_transaction_result(&result))
+    }
+
     pub async fn get_transaction_by_hash(&self, tx_hash: &str) -> Result<Value, Box<dyn Error>> {
         let transaction = TransactionBuilder::new(self)
             .method(TransactionType::TransactionByHash.as_str())
             .tx_hash(tx_hash)
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
         Ok(response)
     }
 
+    /// The execution logs of the transaction `tx_hash`, read from the debug API.
+    /// Only nodes with the debug API enabled serve them.
+    pub async fn get_trace(&self, tx_hash: &str) -> Result<Trace, Box<dyn Error>> {
+        self.debug().get_trace(tx_hash).await
+    }
+
+    pub async fn get_score_api(&self, address: &str) -> Result<ScoreApi, Box<dyn Error>> {
+        self.get_score_api_at(address, BlockId::Latest).await
+    }
+
+    /// The API of the SCORE at `address` as it was at the block `at`.
+    pub async fn get_score_api_at(&self, address: &str, at: BlockId) -> Result<ScoreApi, Box<dyn Error>> {
+        self.request(&GetScoreApi { address: address.to_string(), height: at.height() }).await
+    }
+
+    /// The status of the SCORE at `address` at the block `at`.
+    pub async fn get_score_status(&self, address: &str, at: BlockId) -> Result<ScoreStatus, Box<dyn Error>> {
+        self.request(&GetScoreStatus { address: address.to_string(), height: at.height() }).await
+    }
+
     pub async fn call(&self, score: &str, params: Value) -> Result<Value, Box<dyn Error>> {
-        let transaction = TransactionBuilder::new(self)
+        self.call_at(score, params, BlockId::Latest).await
+    }
+
+    /// Calls a readonly method of `score` on the state of the block `at`.
+    pub async fn call_at(&self, score: &str, params: Value, at: BlockId) -> Result<Value, Box<dyn Error>> {
+        let mut builder = TransactionBuilder::new(self)
             .method(TransactionType::Call.as_str())
             .to(score)
-            .call(params)
-            .build();
+            .call(params);
+        if let Some(height) = at.height() {
+            builder = builder.block_height(&height);
+        }
+        let transaction = builder.build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
         Ok(response)
     }
 
-    pub async fn send_transaction(&self, wallet: Wallet, to: &str, value: &str, version: &str, nid: &str, nonce: &str, step_limit: &str) -> Result<Value, Box<dyn Error>> {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn send_transaction(&self, wallet: Wallet, to: &str, value: &str, version: &str, nid: Option<&str>, nonce: &str, step_limit: &str) -> Result<Value, Box<dyn Error>> {
         let transaction = TransactionBuilder::new(self)
             .method(TransactionType::SendTransaction.as_str())
             .from(wallet.get_public_address().as_str())
             .to(to)
             .value(value)
             .version(version)
-            .nid(nid)
+            .nid_or_default(nid)
             .timestamp()
             .nonce(nonce)
             .step_limit(step_limit)
             .sign(wallet.get_private_key().as_str())
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
         Ok(response)
     }
 
-    pub async fn send_transaction_with_message(&self, wallet: Wallet, to: &str, value: &str, version: &str, nid: &str, nonce: &str, step_limit: &str, message: &str) -> Result<Value, Box<dyn Error>> {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn send_transaction_with_message(&self, wallet: Wallet, to: &str, value: &str, version: &str, nid: Option<&str>, nonce: &str, step_limit: &str, message: &str) -> Result<Value, Box<dyn Error>> {
         let transaction = TransactionBuilder::new(self)
             .method(TransactionType::SendTransaction.as_str())
             .from(wallet.get_public_address().as_str())
             .to(to)
             .value(value)
             .version(version)
-            .nid(nid)
+            .nid_or_default(nid)
             .timestamp()
             .nonce(nonce)
             .step_limit(step_limit)
             .message(message)
             .sign(wallet.get_private_key().as_str())
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
         Ok(response)
     }
+
+    pub async fn send_raw_transaction(&self, signed_transaction: &SignedTransaction) -> Result<Value, Box<dyn Error>> {
+        let transaction = TransactionBuilder::new(self)
+            .method(TransactionType::SendTransaction.as_str())
+            .set_params(signed_transaction.params())
+            .build()?;
+
+        let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
+
+        // Make sure the node accepted the exact transaction we signed
+        if let Some(tx_hash) = response["result"].as_str() {
+            if tx_hash != signed_transaction.tx_hash() {
+                return Err(Box::new(IconError::UnexpectedResponse(format!("transaction hash mismatch: expected {}, got {}", signed_transaction.tx_hash(), tx_hash))));
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Streams new blocks from `from_height` over the node's websocket, reconnecting
+    /// from the last block seen whenever the connection drops.
+    pub fn subscribe_blocks(&self, from_height: u64) -> BoxStream<'static, Result<BlockNotification, IconError>> {
+        websocket::subscribe_blocks(websocket::websocket_url(&self.icon_service_url, "block"), from_height)
+    }
+
+    /// Streams transactions emitting events that match `filter`, from `from_height`,
+    /// with a progress notification every `websocket::PROGRESS_INTERVAL` blocks.
+    pub fn subscribe_events(&self, filter: EventFilter, from_height: u64) -> BoxStream<'static, Result<EventMessage, IconError>> {
+        websocket::subscribe_events(websocket::websocket_url(&self.icon_service_url, "event"), filter, from_height)
+    }
+
+    /// The BTP network `network_id` in the state of the block `at`.
+    pub async fn get_btp_network_info(&self, network_id: &str, at: BlockId) -> Result<BtpNetworkInfo, Box<dyn Error>> {
+        self.request(&GetBtpNetworkInfo { id: network_id.to_string(), height: at.height() }).await
+    }
+
+    /// The BTP network type `network_type_id` in the state of the block `at`.
+    pub async fn get_btp_network_type_info(&self, network_type_id: &str, at: BlockId) -> Result<BtpNetworkTypeInfo, Box<dyn Error>> {
+        self.request(&GetBtpNetworkTypeInfo { id: network_type_id.to_string(), height: at.height() }).await
+    }
+
+    /// The messages sent to the BTP network `network_id` in the block at `block_height`.
+    pub async fn get_btp_messages(&self, block_height: u64, network_id: &str) -> Result<Vec<Vec<u8>>, Box<dyn Error>> {
+        let messages = self.fetch(&GetBtpMessages { height: format!("0x{:x}", block_height), network_id: network_id.to_string() }).await?;
+
+        Ok(messages.iter().map(|message| decode_base64(message)).collect::<Result<Vec<Vec<u8>>, IconError>>()?)
+    }
+
+    pub async fn get_btp_header(&self, block_height: u64, network_id: &str) -> Result<BtpHeader, Box<dyn Error>> {
+        let header = self.fetch(&GetBtpHeader { height: format!("0x{:x}", block_height), network_id: network_id.to_string() }).await?;
+
+        Ok(BtpHeader::from_base64(&header)?)
+    }
+
+    /// The proof of the BTP header at `block_height`, in the format of the network type.
+    pub async fn get_btp_proof(&self, block_height: u64, network_id: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        let proof = self.fetch(&GetBtpProof { height: format!("0x{:x}", block_height), network_id: network_id.to_string() }).await?;
+
+        Ok(decode_base64(&proof)?)
+    }
+
+    pub async fn get_btp_source_information(&self) -> Result<BtpSourceInformation, Box<dyn Error>> {
+        self.request(&GetBtpSourceInformation).await
+    }
+
+    /// Streams the BTP blocks of `network_id` from `from_height`, with their proof if `proof` is set.
+    pub fn subscribe_btp(&self, network_id: u64, proof: bool, from_height: u64) -> BoxStream<'static, Result<BtpNotification, IconError>> {
+        websocket::subscribe_btp(websocket::websocket_url(&self.icon_service_url, "btp"), network_id, proof, from_height)
+    }
+
+    /// Streams blocks in order from `cursor` over JSON-RPC, checking for new blocks every
+    /// `interval` once caught up. An alternative to `subscribe_blocks` where websockets are blocked.
+    pub fn poll_blocks(&self, cursor: BlockCursor, interval: Duration) -> BoxStream<'static, Result<Block, IconError>> {
+        block_stream::poll_blocks(self.clone(), cursor, interval)
+    }
 }
@@ -3,6 +3,7 @@ use std::str::FromStr;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use crate::error::IconError;
 use crate::icon_service::IconService;
 use crate::transaction_builder::TransactionBuilder;
 use crate::utils::helpers::icx_to_hex;
@@ -32,7 +33,7 @@ impl IRC2 {
                     "method": "name",
                 })
             )
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
@@ -48,7 +49,7 @@ impl IRC2 {
                     "method": "symbol",
                 })
             )
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
@@ -64,7 +65,7 @@ impl IRC2 {
                     "method": "decimals",
                 })
             )
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
@@ -80,7 +81,7 @@ impl IRC2 {
                     "method": "totalSupply",
                 })
             )
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
@@ -99,22 +100,23 @@ impl IRC2 {
                     }
                 })
             )
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
         Ok(response)
     }
 
-    pub async fn transfer(&self, wallet: Wallet, to: &str, value: &str, version: &str, nid: &str, nonce: &str, step_limit: &str) -> Result<Value, Box<dyn Error>> {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn transfer(&self, wallet: Wallet, to: &str, value: &str, version: &str, nid: Option<&str>, nonce: &str, step_limit: &str) -> Result<Value, Box<dyn Error>> {
         let mut parsed_value = value.to_string();
 
         if !parsed_value.starts_with("0x") {
-            match icx_to_hex(Decimal::from_str(value).expect("Invalid value")) {
+            match Decimal::from_str(value).ok().and_then(icx_to_hex) {
                 Some(v) => {
                     parsed_value = v;
                 }
-                None => panic!("Failed to convert value to hex"),
+                None => return Err(Box::new(IconError::InvalidTransaction(vec![format!("invalid value: {}", value)]))),
             }
         }
 
@@ -123,7 +125,7 @@ impl IRC2 {
             .from(wallet.get_public_address().as_str())
             .to(&self.contract_address)
             .version(version)
-            .nid(nid)
+            .nid_or_default(nid)
             .timestamp()
             .nonce(nonce)
             .step_limit(step_limit)
@@ -137,7 +139,7 @@ impl IRC2 {
                 })
             )
             .sign(wallet.get_private_key().as_str())
-            .build();
+            .build()?;
 
         let response: Value = transaction.send().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
 
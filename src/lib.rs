@@ -1,6 +1,26 @@
 pub mod utils;
+pub mod error;
 pub mod icon_service;
 pub mod transaction;
 pub mod wallet;
 pub mod transaction_builder;
 pub mod irc2;
+pub mod contract;
+pub mod events;
+pub mod logs_bloom;
+pub mod event_filter;
+pub mod websocket;
+pub mod block_stream;
+pub mod scanner;
+pub mod block_header;
+pub mod proof;
+pub mod btp;
+pub mod rlp;
+pub mod debug;
+pub mod requests;
+pub mod responses;
+pub mod codegen;
+
+// Re-exported for code generated by `codegen`
+pub use num_bigint;
+pub use serde_json;
@@ -0,0 +1,220 @@
+use hex::{decode, encode};
+use num_bigint::BigInt;
+use sha3::{Digest, Sha3_256};
+use crate::error::IconError;
+use crate::events::{EventArg, EventSignature};
+use crate::responses::EventLog;
+use crate::rlp::{Decodable, Encodable, RlpItem};
+
+pub const LOGS_BLOOM_BITS: usize = 2048;
+pub const LOGS_BLOOM_BYTES: usize = LOGS_BLOOM_BITS / 8;
+
+/// The 2048 bit bloom filter of event logs carried by receipts and block headers.
+///
+/// Computed the same way as goloop: the score address is added with a `0xff` prefix and each
+/// indexed item with its position as prefix, setting three bits taken from the SHA3-256 hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogsBloom {
+    bytes: [u8; LOGS_BLOOM_BYTES],
+}
+
+impl Default for LogsBloom {
+    fn default() -> Self {
+        Self {
+            bytes: [0u8; LOGS_BLOOM_BYTES],
+        }
+    }
+}
+
+impl LogsBloom {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads a bloom as found in receipts (`0x` + 256 bytes) or its compact form with leading zeros stripped.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IconError> {
+        if bytes.len() > LOGS_BLOOM_BYTES {
+            return Err(IconError::InvalidEvent(format!("logs bloom too long: {} bytes", bytes.len())));
+        }
+
+        let mut bloom = Self::default();
+        bloom.bytes[LOGS_BLOOM_BYTES - bytes.len()..].copy_from_slice(bytes);
+
+        Ok(bloom)
+    }
+
+    pub fn from_hex(value: &str) -> Result<Self, IconError> {
+        let bytes = decode(value.strip_prefix("0x").unwrap_or(value))
+            .map_err(|_| IconError::InvalidEvent(format!("invalid logs bloom: {}", value)))?;
+
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", encode(self.bytes))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn add(&mut self, data: &[u8]) {
+        for bit in Self::bits(data) {
+            self.bytes[LOGS_BLOOM_BYTES - 1 - bit / 8] |= 1 << (bit % 8);
+        }
+    }
+
+    pub fn contains(&self, data: &[u8]) -> bool {
+        Self::bits(data).iter().all(|bit| self.bytes[LOGS_BLOOM_BYTES - 1 - bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    /// Whether every bit of `other` is set, i.e. whatever was added to `other` may be in this bloom.
+    pub fn contains_bloom(&self, other: &LogsBloom) -> bool {
+        self.bytes.iter().zip(other.bytes.iter()).all(|(a, b)| a & b == *b)
+    }
+
+    pub fn merge(&mut self, other: &LogsBloom) {
+        for (a, b) in self.bytes.iter_mut().zip(other.bytes.iter()) {
+            *a |= b;
+        }
+    }
+
+    pub fn add_score_address(&mut self, score_address: &str) -> Result<(), IconError> {
+        let mut data = vec![0xff];
+        data.extend(address_to_bytes(score_address)?);
+        self.add(&data);
+
+        Ok(())
+    }
+
+    /// Adds an indexed item, already in its binary form, at position `index` (0 being the signature).
+    pub fn add_indexed(&mut self, index: usize, item: &[u8]) {
+        let mut data = vec![index as u8];
+        data.extend_from_slice(item);
+        self.add(&data);
+    }
+
+    pub fn add_event_log(&mut self, log: &EventLog) -> Result<(), IconError> {
+        let signature = log.indexed.first()
+            .and_then(|signature| signature.as_deref())
+            .ok_or_else(|| IconError::InvalidEvent("missing event signature".to_string()))?;
+        let args: Vec<Option<&str>> = log.indexed[1..].iter().map(|value| value.as_deref()).collect();
+
+        self.merge(&event_bloom(Some(&log.score_address), signature, &args)?);
+
+        Ok(())
+    }
+
+    pub fn from_event_logs(logs: &[EventLog]) -> Result<Self, IconError> {
+        let mut bloom = Self::default();
+        for log in logs {
+            bloom.add_event_log(log)?;
+        }
+
+        Ok(bloom)
+    }
+
+    // Three 11 bit indices taken from the first six bytes of the hash
+    fn bits(data: &[u8]) -> [usize; 3] {
+        let hash = Sha3_256::digest(data);
+        let mut bits = [0usize; 3];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = ((hash[i * 2] as usize) << 8 | hash[i * 2 + 1] as usize) & (LOGS_BLOOM_BITS - 1);
+        }
+
+        bits
+    }
+}
+
+impl Encodable for LogsBloom {
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::Bytes(self.bytes.to_vec())
+    }
+}
+
+impl Decodable for LogsBloom {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        Self::from_bytes(item.as_bytes_or_empty()?)
+    }
+}
+
+/// Builds the bloom an event would leave, to test blocks and receipts with `LogsBloom::contains_bloom`.
+///
+/// `indexed_args` are the leading indexed arguments in their JSON form (hex strings, addresses...);
+/// pass `None` to skip a position and only match on the others.
+pub fn event_bloom(score_address: Option<&str>, signature: &str, indexed_args: &[Option<&str>]) -> Result<LogsBloom, IconError> {
+    let parsed = EventSignature::parse(signature)?;
+    if indexed_args.len() > parsed.types.len() {
+        return Err(IconError::InvalidEvent(format!("{} has only {} arguments", signature, parsed.types.len())));
+    }
+
+    let mut bloom = LogsBloom::default();
+    if let Some(score_address) = score_address {
+        bloom.add_score_address(score_address)?;
+    }
+    bloom.add_indexed(0, signature.as_bytes());
+
+    for (i, (arg, arg_type)) in indexed_args.iter().zip(parsed.types.iter()).enumerate() {
+        if let Some(arg) = arg {
+            bloom.add_indexed(i + 1, &event_arg_to_bytes(&EventArg::decode(Some(arg), arg_type)?)?);
+        }
+    }
+
+    Ok(bloom)
+}
+
+/// The binary form goloop stores for an event argument.
+pub fn event_arg_to_bytes(arg: &EventArg) -> Result<Vec<u8>, IconError> {
+    match arg {
+        EventArg::Address(address) => address_to_bytes(address),
+        EventArg::Int(value) => Ok(int_to_bytes(value)),
+        EventArg::Bool(value) => Ok(vec![*value as u8]),
+        EventArg::Bytes(value) => Ok(value.clone()),
+        EventArg::Str(value) => Ok(value.as_bytes().to_vec()),
+        EventArg::Null => Ok(Vec::new()),
+    }
+}
+
+/// `hx...` -> `0x00 || body`, `cx...` -> `0x01 || body`.
+pub fn address_to_bytes(address: &str) -> Result<Vec<u8>, IconError> {
+    let invalid = || IconError::InvalidEvent(format!("invalid address: {}", address));
+
+    let prefix = match address.get(..2) {
+        Some("hx") => 0u8,
+        Some("cx") => 1u8,
+        _ => return Err(invalid()),
+    };
+    let body = decode(&address[2..]).map_err(|_| invalid())?;
+    if body.len() != 20 {
+        return Err(invalid());
+    }
+
+    let mut bytes = vec![prefix];
+    bytes.extend(body);
+
+    Ok(bytes)
+}
+
+/// The inverse of `address_to_bytes`.
+pub fn address_from_bytes(bytes: &[u8]) -> Result<String, IconError> {
+    let prefix = match bytes.first() {
+        Some(0) if bytes.len() == 21 => "hx",
+        Some(1) if bytes.len() == 21 => "cx",
+        _ => return Err(IconError::InvalidEvent(format!("invalid address bytes: 0x{}", encode(bytes)))),
+    };
+
+    Ok(format!("{}{}", prefix, encode(&bytes[1..])))
+}
+
+/// An optional address field of an RLP structure, empty or `Nil` when absent.
+pub(crate) fn decode_address(item: &RlpItem) -> Result<Option<String>, IconError> {
+    match item.as_bytes_or_empty()? {
+        [] => Ok(None),
+        address => Ok(Some(address_from_bytes(address)?)),
+    }
+}
+
+// Minimal big endian two's complement, zero being a single zero byte
+pub(crate) fn int_to_bytes(value: &BigInt) -> Vec<u8> {
+    value.to_signed_bytes_be()
+}
@@ -0,0 +1,232 @@
+use base64::{Engine as _, engine::general_purpose as base64_encoder};
+use hex::decode;
+use num_bigint::BigInt;
+use sha3::{Digest, Sha3_256};
+use crate::block_header::BlockHeader;
+use crate::error::IconError;
+use crate::events::{EventArg, EventSignature};
+use crate::logs_bloom::{address_to_bytes, decode_address, event_arg_to_bytes, LogsBloom};
+use crate::responses::EventLog;
+use crate::rlp::{decode_field, Decodable, Encodable, RlpItem};
+
+/// Decodes the nodes of a proof as returned by the node, hex or base64 encoded.
+pub fn decode_proof(nodes: &[String]) -> Result<Vec<Vec<u8>>, IconError> {
+    nodes.iter()
+        .map(|node| {
+            let decoded = match node.strip_prefix("0x") {
+                Some(node) => decode(node).ok(),
+                None => base64_encoder::STANDARD.decode(node).ok(),
+            };
+            decoded.ok_or_else(|| IconError::UnexpectedResponse(format!("invalid proof node: {}", node)))
+        })
+        .collect()
+}
+
+/// The trie key of the receipt or event log at `index`.
+pub fn proof_key(index: u64) -> Vec<u8> {
+    index.rlp_bytes()
+}
+
+/// Walks a Merkle Patricia proof from `root` along `key` and returns the value found at the end.
+///
+/// Each node of `proof` must hash to the link followed from the previous one, so the value is
+/// as trustworthy as `root`.
+pub fn verify_merkle_proof(root: &[u8], key: &[u8], proof: &[Vec<u8>]) -> Result<Vec<u8>, IconError> {
+    let nibbles: Vec<u8> = key.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]).collect();
+    let mut path = &nibbles[..];
+    let mut nodes = proof.iter();
+
+    let mut link = RlpItem::Bytes(root.to_vec());
+    loop {
+        let node = match &link {
+            RlpItem::Bytes(hash) if hash.len() == 32 => {
+                let bytes = nodes.next().ok_or_else(|| IconError::Verification("proof too short".to_string()))?;
+                if Sha3_256::digest(bytes).as_slice() != hash.as_slice() {
+                    return Err(IconError::Verification("proof node does not match its hash".to_string()));
+                }
+                RlpItem::decode(bytes)?
+            },
+            // Nodes shorter than a hash are embedded in their parent
+            RlpItem::List(_) => link.clone(),
+            _ => return Err(IconError::Verification("key not in the trie".to_string())),
+        };
+
+        let items = node.as_list()?;
+        match items.len() {
+            17 => {
+                match path.split_first() {
+                    Some((nibble, rest)) => {
+                        link = items[*nibble as usize].clone();
+                        path = rest;
+                    },
+                    None => return value_of(&items[16]),
+                }
+            },
+            2 => {
+                let (is_leaf, node_path) = decode_hex_prefix(items[0].as_bytes()?)?;
+                if !path.starts_with(&node_path) {
+                    return Err(IconError::Verification("key not in the trie".to_string()));
+                }
+                path = &path[node_path.len()..];
+
+                if is_leaf {
+                    if !path.is_empty() {
+                        return Err(IconError::Verification("key not in the trie".to_string()));
+                    }
+                    return value_of(&items[1]);
+                }
+                link = items[1].clone();
+            },
+            count => return Err(IconError::Rlp(format!("invalid trie node with {} items", count))),
+        }
+    }
+}
+
+fn value_of(item: &RlpItem) -> Result<Vec<u8>, IconError> {
+    match item.as_bytes_or_empty()? {
+        [] => Err(IconError::Verification("key not in the trie".to_string())),
+        value => Ok(value.to_vec()),
+    }
+}
+
+// Returns whether the node is a leaf and its path nibbles
+fn decode_hex_prefix(encoded: &[u8]) -> Result<(bool, Vec<u8>), IconError> {
+    let first = *encoded.first().ok_or_else(|| IconError::Rlp("empty trie node path".to_string()))?;
+    let flags = first >> 4;
+    let mut nibbles = Vec::new();
+    if flags & 0x1 != 0 {
+        nibbles.push(first & 0x0f);
+    }
+    nibbles.extend(encoded[1..].iter().flat_map(|byte| [byte >> 4, byte & 0x0f]));
+
+    Ok((flags & 0x2 != 0, nibbles))
+}
+
+/// A receipt as stored in the receipt trie of a block.
+///
+/// Recent receipts only commit to their event logs through `event_logs_hash`, the root of
+/// the trie proven by `icx_getProofForEvents`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProvenReceipt {
+    pub status: i64,
+    pub to: Option<String>,
+    pub cumulative_step_used: BigInt,
+    pub step_used: BigInt,
+    pub step_price: BigInt,
+    pub logs_bloom: LogsBloom,
+    pub event_logs: Vec<ProvenEventLog>,
+    pub score_address: Option<String>,
+    pub event_logs_hash: Vec<u8>,
+}
+
+impl ProvenReceipt {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IconError> {
+        Self::from_rlp_bytes(bytes)
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.status == 1
+    }
+}
+
+impl Decodable for ProvenReceipt {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        let fields = item.as_list()?;
+        if fields.len() < 7 {
+            return Err(IconError::Rlp(format!("receipt has {} fields, expected at least 7", fields.len())));
+        }
+        let mut fields = fields.iter();
+
+        Ok(Self {
+            status: decode_field(&mut fields)?,
+            to: decode_address(fields.next().unwrap_or(&RlpItem::Nil))?,
+            cumulative_step_used: decode_field(&mut fields)?,
+            step_used: decode_field(&mut fields)?,
+            step_price: decode_field(&mut fields)?,
+            logs_bloom: decode_field(&mut fields)?,
+            event_logs: decode_field(&mut fields)?,
+            score_address: decode_address(fields.next().unwrap_or(&RlpItem::Nil))?,
+            event_logs_hash: decode_field(&mut fields)?,
+        })
+    }
+}
+
+/// An event log as stored in a receipt or the event log trie, values in their binary form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProvenEventLog {
+    pub score_address: String,
+    pub indexed: Vec<Option<Vec<u8>>>,
+    pub data: Vec<Option<Vec<u8>>>,
+}
+
+impl ProvenEventLog {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IconError> {
+        Self::from_rlp_bytes(bytes)
+    }
+
+    /// Whether `log`, as returned in a transaction result, is this event log.
+    pub fn matches(&self, log: &EventLog) -> bool {
+        let signature = match log.indexed.first().and_then(|signature| signature.as_deref()) {
+            Some(signature) => signature,
+            None => return false,
+        };
+        let types = match EventSignature::parse(signature) {
+            Ok(parsed) => parsed.types,
+            Err(_) => return false,
+        };
+        if log.indexed.len() != self.indexed.len() || log.data.len() != self.data.len() || self.indexed.len() + self.data.len() != types.len() + 1 {
+            return false;
+        }
+        if address_to_bytes(&log.score_address).ok() != address_to_bytes(&self.score_address).ok() {
+            return false;
+        }
+        if self.indexed[0].as_deref() != Some(signature.as_bytes()) {
+            return false;
+        }
+
+        let expected = self.indexed[1..].iter().chain(self.data.iter());
+        let actual = log.indexed[1..].iter().chain(log.data.iter());
+        expected.zip(actual).zip(types.iter()).all(|((expected, actual), arg_type)| match (expected, actual) {
+            (None, None) => true,
+            (Some(expected), Some(actual)) => EventArg::decode(Some(actual), arg_type).ok()
+                .and_then(|arg| event_arg_to_bytes(&arg).ok())
+                .is_some_and(|actual| actual == *expected),
+            _ => false,
+        })
+    }
+}
+
+impl Decodable for ProvenEventLog {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        let fields = item.as_list()?;
+        if fields.len() < 3 {
+            return Err(IconError::Rlp(format!("event log has {} fields, expected 3", fields.len())));
+        }
+        let mut fields = fields.iter();
+
+        Ok(Self {
+            score_address: decode_address(fields.next().unwrap_or(&RlpItem::Nil))?
+                .ok_or_else(|| IconError::Rlp("event log without score address".to_string()))?,
+            indexed: decode_field(&mut fields)?,
+            data: decode_field(&mut fields)?,
+        })
+    }
+}
+
+/// Verifies the proof of the receipt at `index` against the normal receipt hash of `header`.
+///
+/// Receipts are committed by the result of the next block, so `header` must be the header of
+/// the block following the one holding the transaction.
+pub fn verify_result_proof(header: &BlockHeader, index: u64, proof: &[Vec<u8>]) -> Result<ProvenReceipt, IconError> {
+    let root = header.block_result()?.normal_receipt_hash;
+    let value = verify_merkle_proof(&root, &proof_key(index), proof)?;
+
+    ProvenReceipt::from_bytes(&value)
+}
+
+/// Verifies the proof of the event log at `index` against the event logs hash of `receipt`.
+pub fn verify_event_proof(receipt: &ProvenReceipt, index: u64, proof: &[Vec<u8>]) -> Result<ProvenEventLog, IconError> {
+    let value = verify_merkle_proof(&receipt.event_logs_hash, &proof_key(index), proof)?;
+
+    ProvenEventLog::from_bytes(&value)
+}
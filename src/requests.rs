@@ -0,0 +1,534 @@
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+use crate::responses::{Block, BtpNetworkInfo, BtpNetworkTypeInfo, BtpSourceInformation, NetworkInfo, DebugAccount, ScoreApi, ScoreStatus, Trace, TransactionInfo, TransactionResult};
+use crate::transaction::SignedTransaction;
+use crate::utils::transaction_types::TransactionType;
+
+/// A typed ICON JSON-RPC v3 request, sent with `IconService::request`.
+///
+/// `Response` is the type the `result` field of the node's reply is decoded into.
+pub trait Request {
+    type Response: DeserializeOwned;
+
+    fn method(&self) -> TransactionType;
+
+    fn params(&self) -> Map<String, Value> {
+        Map::new()
+    }
+}
+
+fn params(entries: Vec<(&str, Value)>) -> Map<String, Value> {
+    entries.into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+}
+
+// Adds the optional `height` of state queries
+fn with_height(mut params: Map<String, Value>, height: &Option<String>) -> Map<String, Value> {
+    if let Some(height) = height {
+        params.insert("height".to_string(), json!(height));
+    }
+
+    params
+}
+
+/// The block a state query reads the state of, the last one by default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlockId {
+    #[default]
+    Latest,
+    Height(u64),
+}
+
+impl BlockId {
+    /// The `height` parameter of the query, `None` for the last block.
+    pub fn height(&self) -> Option<String> {
+        match self {
+            BlockId::Latest => None,
+            BlockId::Height(height) => Some(format!("0x{:x}", height)),
+        }
+    }
+}
+
+impl From<u64> for BlockId {
+    fn from(height: u64) -> Self {
+        BlockId::Height(height)
+    }
+}
+
+pub struct GetLastBlock;
+
+impl Request for GetLastBlock {
+    type Response = Block;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::LastBlock
+    }
+}
+
+pub struct GetBlockByHeight {
+    pub height: String,
+}
+
+impl Request for GetBlockByHeight {
+    type Response = Block;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::BlockByHeight
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("height", json!(self.height))])
+    }
+}
+
+pub struct GetBlockByHash {
+    pub hash: String,
+}
+
+impl Request for GetBlockByHash {
+    type Response = Block;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::BlockByHash
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("hash", json!(self.hash))])
+    }
+}
+
+pub struct GetTransactionResult {
+    pub tx_hash: String,
+}
+
+impl Request for GetTransactionResult {
+    type Response = TransactionResult;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::TransactionResult
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("txHash", json!(self.tx_hash))])
+    }
+}
+
+pub struct WaitTransactionResult {
+    pub tx_hash: String,
+}
+
+impl Request for WaitTransactionResult {
+    type Response = TransactionResult;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::WaitTransactionResult
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("txHash", json!(self.tx_hash))])
+    }
+}
+
+pub struct GetTransactionByHash {
+    pub tx_hash: String,
+}
+
+impl Request for GetTransactionByHash {
+    type Response = TransactionInfo;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::TransactionByHash
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("txHash", json!(self.tx_hash))])
+    }
+}
+
+pub struct GetBalance {
+    pub address: String,
+    pub height: Option<String>,
+}
+
+impl Request for GetBalance {
+    type Response = String;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::Balance
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        with_height(params(vec![("address", json!(self.address))]), &self.height)
+    }
+}
+
+pub struct GetTotalSupply;
+
+impl Request for GetTotalSupply {
+    type Response = String;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::TotalSupply
+    }
+}
+
+pub struct GetScoreApi {
+    pub address: String,
+    pub height: Option<String>,
+}
+
+impl Request for GetScoreApi {
+    type Response = ScoreApi;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::ScoreApi
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        with_height(params(vec![("address", json!(self.address))]), &self.height)
+    }
+}
+
+/// The status of the SCORE at `address`, at `height` if given or else at the last block.
+pub struct GetScoreStatus {
+    pub address: String,
+    pub height: Option<String>,
+}
+
+impl Request for GetScoreStatus {
+    type Response = ScoreStatus;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::ScoreStatus
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        with_height(params(vec![("address", json!(self.address))]), &self.height)
+    }
+}
+
+/// A readonly SCORE call. `data` holds the `method` and `params` of the call, run on the state at `height` if given.
+pub struct Call {
+    pub from: Option<String>,
+    pub to: String,
+    pub data: Value,
+    pub height: Option<String>,
+}
+
+impl Request for Call {
+    type Response = Value;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::Call
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        let mut params = params(vec![
+            ("to", json!(self.to)),
+            ("dataType", json!("call")),
+            ("data", self.data.clone()),
+        ]);
+        if let Some(from) = &self.from {
+            params.insert("from".to_string(), json!(from));
+        }
+
+        with_height(params, &self.height)
+    }
+}
+
+/// Broadcasts a signed transaction, returning its hash.
+pub struct SendTransaction {
+    pub transaction: SignedTransaction,
+}
+
+impl Request for SendTransaction {
+    type Response = String;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::SendTransaction
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        self.transaction.params().clone()
+    }
+}
+
+/// Broadcasts a signed transaction and waits for its result.
+pub struct SendTransactionAndWait {
+    pub transaction: SignedTransaction,
+}
+
+impl Request for SendTransactionAndWait {
+    type Response = TransactionResult;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::SendTransactionAndWait
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        self.transaction.params().clone()
+    }
+}
+
+/// Returns the base64 encoded data stored under `hash`.
+pub struct GetDataByHash {
+    pub hash: String,
+}
+
+impl Request for GetDataByHash {
+    type Response = String;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::DataByHash
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("hash", json!(self.hash))])
+    }
+}
+
+/// Returns the base64 encoded, RLP serialized block header at `height`.
+pub struct GetBlockHeaderByHeight {
+    pub height: String,
+}
+
+impl Request for GetBlockHeaderByHeight {
+    type Response = String;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::BlockHeaderByHeight
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("height", json!(self.height))])
+    }
+}
+
+/// Returns the base64 encoded, RLP serialized commit votes for the block at `height`.
+pub struct GetVotesByHeight {
+    pub height: String,
+}
+
+impl Request for GetVotesByHeight {
+    type Response = String;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::VotesByHeight
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("height", json!(self.height))])
+    }
+}
+
+/// Returns the nodes, hex encoded, of the Merkle Patricia proof of the receipt at `index` in the block `hash`.
+pub struct GetProofForResult {
+    pub hash: String,
+    pub index: String,
+}
+
+impl Request for GetProofForResult {
+    type Response = Vec<String>;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::ProofForResult
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("hash", json!(self.hash)), ("index", json!(self.index))])
+    }
+}
+
+/// Returns the receipt proof followed by one proof per requested event index.
+pub struct GetProofForEvents {
+    pub hash: String,
+    pub index: String,
+    pub events: Vec<String>,
+}
+
+impl Request for GetProofForEvents {
+    type Response = Vec<Vec<String>>;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::ProofForEvents
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("hash", json!(self.hash)), ("index", json!(self.index)), ("events", json!(self.events))])
+    }
+}
+
+pub struct GetNetworkInfo;
+
+impl Request for GetNetworkInfo {
+    type Response = NetworkInfo;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::NetworkInfo
+    }
+}
+
+/// The BTP network `id`, at `height` if given or else at the last block.
+pub struct GetBtpNetworkInfo {
+    pub id: String,
+    pub height: Option<String>,
+}
+
+impl Request for GetBtpNetworkInfo {
+    type Response = BtpNetworkInfo;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::BtpNetworkInfo
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        btp_params(&self.id, &self.height)
+    }
+}
+
+/// The BTP network type `id`, at `height` if given or else at the last block.
+pub struct GetBtpNetworkTypeInfo {
+    pub id: String,
+    pub height: Option<String>,
+}
+
+impl Request for GetBtpNetworkTypeInfo {
+    type Response = BtpNetworkTypeInfo;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::BtpNetworkTypeInfo
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        btp_params(&self.id, &self.height)
+    }
+}
+
+fn btp_params(id: &str, height: &Option<String>) -> Map<String, Value> {
+    with_height(params(vec![("id", json!(id))]), height)
+}
+
+/// Returns the base64 encoded messages of the BTP network `network_id` at `height`.
+pub struct GetBtpMessages {
+    pub height: String,
+    pub network_id: String,
+}
+
+impl Request for GetBtpMessages {
+    type Response = Vec<String>;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::BtpMessages
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("height", json!(self.height)), ("networkID", json!(self.network_id))])
+    }
+}
+
+/// Returns the base64 encoded BTP block header of the network `network_id` at `height`.
+pub struct GetBtpHeader {
+    pub height: String,
+    pub network_id: String,
+}
+
+impl Request for GetBtpHeader {
+    type Response = String;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::BtpHeader
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("height", json!(self.height)), ("networkID", json!(self.network_id))])
+    }
+}
+
+/// Returns the base64 encoded proof of the BTP block header of the network `network_id` at `height`.
+pub struct GetBtpProof {
+    pub height: String,
+    pub network_id: String,
+}
+
+impl Request for GetBtpProof {
+    type Response = String;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::BtpProof
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("height", json!(self.height)), ("networkID", json!(self.network_id))])
+    }
+}
+
+pub struct GetBtpSourceInformation;
+
+impl Request for GetBtpSourceInformation {
+    type Response = BtpSourceInformation;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::BtpSourceInformation
+    }
+}
+
+/// The execution trace of the transaction `tx_hash`, served by the debug API.
+pub struct GetTrace {
+    pub tx_hash: String,
+}
+
+impl Request for GetTrace {
+    type Response = Trace;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::Trace
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        params(vec![("txHash", json!(self.tx_hash))])
+    }
+}
+
+/// The account at `address` as stored in the state at `height`, served by the debug API.
+/// `filter` is the bitmask of the parts of the account to include, all of them by default.
+pub struct GetDebugAccount {
+    pub address: String,
+    pub height: Option<String>,
+    pub filter: Option<String>,
+}
+
+impl Request for GetDebugAccount {
+    type Response = DebugAccount;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::DebugAccount
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        let mut params = with_height(params(vec![("address", json!(self.address))]), &self.height);
+        if let Some(filter) = &self.filter {
+            params.insert("filter".to_string(), json!(filter));
+        }
+
+        params
+    }
+}
+
+/// The steps an unsigned transaction would use, served by the debug API.
+/// `params` are those of `icx_sendTransaction` without `stepLimit` and `signature`.
+pub struct EstimateStep {
+    pub params: Map<String, Value>,
+}
+
+impl Request for EstimateStep {
+    type Response = String;
+
+    fn method(&self) -> TransactionType {
+        TransactionType::EstimateStep
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        self.params.clone()
+    }
+}
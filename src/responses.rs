@@ -0,0 +1,389 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use crate::error::IconError;
+
+/// A block as returned by `icx_getLastBlock`, `icx_getBlockByHeight` and `icx_getBlockByHash`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Block {
+    pub version: String,
+    pub height: u64,
+    pub block_hash: String,
+    pub prev_block_hash: String,
+    pub merkle_tree_root_hash: String,
+    pub time_stamp: u64,
+    #[serde(default)]
+    pub confirmed_transaction_list: Vec<TransactionInfo>,
+    #[serde(default)]
+    pub peer_id: Option<String>,
+    #[serde(default)]
+    pub signature: Option<String>,
+    #[serde(default)]
+    pub next_leader: Option<String>,
+}
+
+/// A transaction as returned by `icx_getTransactionByHash` or listed in a block.
+///
+/// Blocks may also contain base and genesis transactions, so most fields are optional.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionInfo {
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub from: Option<String>,
+    #[serde(default)]
+    pub to: Option<String>,
+    #[serde(default)]
+    pub value: Option<String>,
+    #[serde(default)]
+    pub step_limit: Option<String>,
+    #[serde(default)]
+    pub timestamp: Option<Value>,
+    #[serde(default)]
+    pub nid: Option<String>,
+    #[serde(default)]
+    pub nonce: Option<String>,
+    #[serde(alias = "tx_hash")]
+    pub tx_hash: String,
+    #[serde(default)]
+    pub tx_index: Option<String>,
+    #[serde(default)]
+    pub block_height: Option<String>,
+    #[serde(default)]
+    pub block_hash: Option<String>,
+    #[serde(default)]
+    pub signature: Option<String>,
+    #[serde(default)]
+    pub data_type: Option<String>,
+    #[serde(default)]
+    pub data: Option<Value>,
+}
+
+/// The receipt of a transaction, returned by `icx_getTransactionResult` and `icx_waitTransactionResult`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionResult {
+    pub status: String,
+    #[serde(default)]
+    pub to: Option<String>,
+    pub tx_hash: String,
+    pub tx_index: String,
+    pub block_height: String,
+    pub block_hash: String,
+    pub cumulative_step_used: String,
+    pub step_used: String,
+    pub step_price: String,
+    #[serde(default)]
+    pub score_address: Option<String>,
+    #[serde(default)]
+    pub event_logs: Vec<EventLog>,
+    #[serde(default)]
+    pub logs_bloom: Option<String>,
+    #[serde(default)]
+    pub failure: Option<Failure>,
+}
+
+impl TransactionResult {
+    pub fn is_success(&self) -> bool {
+        self.status == "0x1"
+    }
+}
+
+/// An event emitted by a SCORE while executing a transaction.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLog {
+    pub score_address: String,
+    pub indexed: Vec<Option<String>>,
+    #[serde(default)]
+    pub data: Vec<Option<String>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Failure {
+    pub code: String,
+    pub message: String,
+}
+
+/// The ABI of a SCORE, as returned by `icx_getScoreApi`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ScoreApi {
+    pub entries: Vec<ScoreApiEntry>,
+}
+
+impl ScoreApi {
+    /// Parses a saved `icx_getScoreApi` result.
+    pub fn from_json(json: &str) -> Result<Self, IconError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn functions(&self) -> impl Iterator<Item = &ScoreApiEntry> {
+        self.entries.iter().filter(|entry| entry.entry_type == ScoreApiType::Function)
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &ScoreApiEntry> {
+        self.entries.iter().filter(|entry| entry.entry_type == ScoreApiType::EventLog)
+    }
+
+    pub fn fallback(&self) -> Option<&ScoreApiEntry> {
+        self.entries.iter().find(|entry| entry.entry_type == ScoreApiType::Fallback)
+    }
+
+    pub fn function(&self, name: &str) -> Option<&ScoreApiEntry> {
+        self.functions().find(|entry| entry.name == name)
+    }
+
+    pub fn event(&self, name: &str) -> Option<&ScoreApiEntry> {
+        self.events().find(|entry| entry.name == name)
+    }
+}
+
+/// A function, fallback or event log of a SCORE.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScoreApiEntry {
+    #[serde(rename = "type")]
+    pub entry_type: ScoreApiType,
+    pub name: String,
+    #[serde(default)]
+    pub inputs: Vec<ScoreApiParam>,
+    #[serde(default)]
+    pub outputs: Vec<ScoreApiParam>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub readonly: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payable: Option<String>,
+}
+
+impl ScoreApiEntry {
+    pub fn is_readonly(&self) -> bool {
+        self.readonly.as_deref() == Some("0x1")
+    }
+
+    pub fn is_payable(&self) -> bool {
+        self.payable.as_deref() == Some("0x1")
+    }
+
+    /// The event signature used in event logs, e.g. `Transfer(Address,Address,int,bytes)`.
+    pub fn signature(&self) -> String {
+        let types: Vec<&str> = self.inputs.iter().map(|input| input.param_type.as_str()).collect();
+        format!("{}({})", self.name, types.join(","))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoreApiType {
+    Function,
+    Fallback,
+    EventLog,
+}
+
+/// An input or output of a SCORE API entry. Outputs have no name.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScoreApiParam {
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "type")]
+    pub param_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed: Option<String>,
+    // Present (possibly `null`) for optional inputs, absent for required ones
+    #[serde(default, deserialize_with = "deserialize_present", skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<ScoreApiParam>>,
+}
+
+impl ScoreApiParam {
+    pub fn is_indexed(&self) -> bool {
+        self.indexed.as_deref() == Some("0x1")
+    }
+
+    /// Inputs with a default value may be omitted from calls.
+    pub fn is_optional(&self) -> bool {
+        self.default.is_some()
+    }
+}
+
+/// The status of a SCORE, as returned by `icx_getScoreStatus`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreStatus {
+    pub owner: String,
+    #[serde(default)]
+    pub current: Option<ScoreContract>,
+    #[serde(default)]
+    pub next: Option<ScoreContract>,
+    #[serde(default)]
+    pub deposit: Option<Value>,
+    #[serde(default)]
+    pub blocked: Option<String>,
+    #[serde(default)]
+    pub disabled: Option<String>,
+    #[serde(default)]
+    pub use_system_deposit: Option<String>,
+}
+
+impl ScoreStatus {
+    pub fn is_blocked(&self) -> bool {
+        self.blocked.as_deref() == Some("0x1")
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.disabled.as_deref() == Some("0x1")
+    }
+
+    /// Whether the SCORE can be called: deployed, accepted, and neither blocked nor disabled.
+    pub fn is_active(&self) -> bool {
+        !self.is_blocked() && !self.is_disabled()
+            && self.current.as_ref().is_some_and(|current| current.status.as_deref().is_none_or(|status| status == "active"))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreContract {
+    #[serde(rename = "type")]
+    pub contract_type: String,
+    pub code_hash: String,
+    pub deploy_tx_hash: String,
+    #[serde(default)]
+    pub audit_tx_hash: Option<String>,
+    /// `active`, `pending` or `rejected` on networks auditing deployments.
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// The network a node belongs to, as returned by `icx_getNetworkInfo`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkInfo {
+    pub platform: String,
+    pub nid: String,
+    pub channel: String,
+    pub earliest: String,
+    #[serde(default)]
+    pub latest: Option<String>,
+    #[serde(default)]
+    pub step_price: Option<String>,
+}
+
+/// A BTP network, as returned by `btp_getNetworkInfo`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BtpNetworkInfo {
+    #[serde(rename = "networkID")]
+    pub network_id: String,
+    pub network_name: String,
+    #[serde(rename = "networkTypeID")]
+    pub network_type_id: String,
+    pub network_type_name: String,
+    pub start_height: String,
+    pub open: String,
+    pub owner: String,
+    #[serde(rename = "nextMessageSN")]
+    pub next_message_sn: String,
+    pub next_proof_context_changed: String,
+    #[serde(rename = "prevNSHash")]
+    pub prev_ns_hash: String,
+    #[serde(rename = "lastNSHash")]
+    pub last_ns_hash: String,
+}
+
+/// A BTP network type, as returned by `btp_getNetworkTypeInfo`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BtpNetworkTypeInfo {
+    #[serde(rename = "networkTypeID")]
+    pub network_type_id: String,
+    pub network_type_name: String,
+    pub next_proof_context: String,
+    #[serde(rename = "openNetworkIDs", default)]
+    pub open_network_ids: Vec<String>,
+}
+
+/// The BTP identity of the chain, as returned by `btp_getSourceInformation`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BtpSourceInformation {
+    #[serde(rename = "srcNetworkUID")]
+    pub src_network_uid: String,
+    #[serde(rename = "networkTypeIDs", default)]
+    pub network_type_ids: Vec<String>,
+}
+
+/// The execution trace of a transaction, as returned by `debug_getTrace`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Trace {
+    #[serde(default)]
+    pub logs: Vec<TraceLog>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub failure: Option<Failure>,
+}
+
+/// An account as stored in the state, as returned by `debug_getAccount`.
+///
+/// Which parts are present depends on the filter of the query; the ones not modeled here are kept in `other`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugAccount {
+    #[serde(default)]
+    pub balance: Option<String>,
+    #[serde(default)]
+    pub is_contract: Option<String>,
+    #[serde(default)]
+    pub contract: Option<Value>,
+    #[serde(default)]
+    pub storage: Option<Value>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, Value>,
+}
+
+/// A line logged while executing a transaction.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TraceLog {
+    pub level: TraceLevel,
+    #[serde(rename = "msg")]
+    pub message: String,
+    /// Microseconds, as reported by the node.
+    #[serde(rename = "ts")]
+    pub timestamp: u64,
+}
+
+/// Verbosity of a trace log, sent by the node as a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum TraceLevel {
+    Debug,
+    Trace,
+    System,
+    Other(u8),
+}
+
+impl From<u8> for TraceLevel {
+    fn from(level: u8) -> Self {
+        match level {
+            0 => TraceLevel::Debug,
+            1 => TraceLevel::Trace,
+            2 => TraceLevel::System,
+            level => TraceLevel::Other(level),
+        }
+    }
+}
+
+impl From<TraceLevel> for u8 {
+    fn from(level: TraceLevel) -> Self {
+        match level {
+            TraceLevel::Debug => 0,
+            TraceLevel::Trace => 1,
+            TraceLevel::System => 2,
+            TraceLevel::Other(level) => level,
+        }
+    }
+}
+
+fn deserialize_present<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
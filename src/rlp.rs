@@ -0,0 +1,313 @@
+use num_bigint::BigInt;
+use crate::error::IconError;
+
+/// Deepest list nesting `RlpItem::decode` accepts, far above what goloop structures use,
+/// so nested lists from an untrusted node can't exhaust the stack.
+pub const MAX_DEPTH: usize = 64;
+
+/// A decoded RLP item.
+///
+/// ICON extends RLP with `Nil` for absent values, encoded as the empty long list `0xf800`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RlpItem {
+    Bytes(Vec<u8>),
+    List(Vec<RlpItem>),
+    Nil,
+}
+
+impl RlpItem {
+    /// Decodes a single item spanning all of `data`.
+    pub fn decode(data: &[u8]) -> Result<Self, IconError> {
+        let (item, rest) = Self::decode_item(data, 0)?;
+        if !rest.is_empty() {
+            return Err(IconError::Rlp(format!("{} trailing bytes", rest.len())));
+        }
+
+        Ok(item)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            RlpItem::Bytes(bytes) if bytes.len() == 1 && bytes[0] < 0x80 => bytes.clone(),
+            RlpItem::Bytes(bytes) => {
+                let mut encoded = encode_length(bytes.len(), 0x80);
+                encoded.extend_from_slice(bytes);
+                encoded
+            },
+            RlpItem::List(items) => {
+                let payload: Vec<u8> = items.iter().flat_map(RlpItem::encode).collect();
+                let mut encoded = encode_length(payload.len(), 0xc0);
+                encoded.extend(payload);
+                encoded
+            },
+            RlpItem::Nil => vec![0xf8, 0x00],
+        }
+    }
+
+    pub fn is_nil(&self) -> bool {
+        matches!(self, RlpItem::Nil)
+    }
+
+    pub fn as_bytes(&self) -> Result<&[u8], IconError> {
+        match self {
+            RlpItem::Bytes(bytes) => Ok(bytes),
+            _ => Err(IconError::Rlp("expected bytes".to_string())),
+        }
+    }
+
+    /// Bytes, with `Nil` read as empty.
+    pub fn as_bytes_or_empty(&self) -> Result<&[u8], IconError> {
+        match self {
+            RlpItem::Nil => Ok(&[]),
+            _ => self.as_bytes(),
+        }
+    }
+
+    pub fn as_list(&self) -> Result<&[RlpItem], IconError> {
+        match self {
+            RlpItem::List(items) => Ok(items),
+            _ => Err(IconError::Rlp("expected a list".to_string())),
+        }
+    }
+
+    fn decode_item(data: &[u8], depth: usize) -> Result<(Self, &[u8]), IconError> {
+        let prefix = *data.first().ok_or_else(|| IconError::Rlp("unexpected end of input".to_string()))?;
+
+        match prefix {
+            0x00..=0x7f => Ok((RlpItem::Bytes(vec![prefix]), &data[1..])),
+            0x80..=0xbf => {
+                let (payload, rest) = split_payload(data, 0x80)?;
+                Ok((RlpItem::Bytes(payload.to_vec()), rest))
+            },
+            _ => {
+                if data.len() >= 2 && data[0] == 0xf8 && data[1] == 0x00 {
+                    return Ok((RlpItem::Nil, &data[2..]));
+                }
+
+                if depth >= MAX_DEPTH {
+                    return Err(IconError::Rlp(format!("lists nested deeper than {}", MAX_DEPTH)));
+                }
+
+                let (mut payload, rest) = split_payload(data, 0xc0)?;
+                let mut items = Vec::new();
+                while !payload.is_empty() {
+                    let (item, remaining) = Self::decode_item(payload, depth + 1)?;
+                    items.push(item);
+                    payload = remaining;
+                }
+
+                Ok((RlpItem::List(items), rest))
+            },
+        }
+    }
+}
+
+/// A value with an RLP representation, see `rlp_struct!` to implement it for a struct.
+pub trait Encodable {
+    fn to_rlp(&self) -> RlpItem;
+
+    fn rlp_bytes(&self) -> Vec<u8> {
+        self.to_rlp().encode()
+    }
+}
+
+/// A value that can be read back from RLP.
+///
+/// Like goloop, `Nil` decodes as the zero value of the type: `0`, empty bytes or lists, `None`.
+pub trait Decodable: Sized {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError>;
+
+    fn from_rlp_bytes(bytes: &[u8]) -> Result<Self, IconError> {
+        Self::from_rlp(&RlpItem::decode(bytes)?)
+    }
+}
+
+/// Reads the next field of a struct, fields missing at the end of the list being read from `Nil`.
+pub fn decode_field<'a, T: Decodable>(fields: &mut impl Iterator<Item = &'a RlpItem>) -> Result<T, IconError> {
+    T::from_rlp(fields.next().unwrap_or(&RlpItem::Nil))
+}
+
+/// Implements `Encodable` and `Decodable` for a struct, encoded as the list of the given fields in order.
+///
+/// This stands in for a derive: the crate has no proc-macro, so structs list their fields here instead.
+/// Every field must itself be `Encodable` and `Decodable`.
+///
+/// ```
+/// use icon_sdk::rlp_struct;
+/// use icon_sdk::rlp::{Decodable, Encodable};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct PartSetId {
+///     count: u32,
+///     hash: Vec<u8>,
+/// }
+///
+/// rlp_struct!(PartSetId { count, hash });
+///
+/// let part_set_id = PartSetId { count: 1, hash: vec![0xab; 32] };
+/// assert_eq!(PartSetId::from_rlp_bytes(&part_set_id.rlp_bytes()).unwrap(), part_set_id);
+/// ```
+#[macro_export]
+macro_rules! rlp_struct {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl $crate::rlp::Encodable for $name {
+            fn to_rlp(&self) -> $crate::rlp::RlpItem {
+                $crate::rlp::RlpItem::List(vec![$($crate::rlp::Encodable::to_rlp(&self.$field)),*])
+            }
+        }
+
+        impl $crate::rlp::Decodable for $name {
+            fn from_rlp(item: &$crate::rlp::RlpItem) -> Result<Self, $crate::error::IconError> {
+                #[allow(unused_mut, unused_variables)]
+                let mut fields = item.as_list()?.iter();
+                Ok(Self {
+                    $($field: $crate::rlp::decode_field(&mut fields)?),*
+                })
+            }
+        }
+    };
+}
+
+impl Encodable for RlpItem {
+    fn to_rlp(&self) -> RlpItem {
+        self.clone()
+    }
+}
+
+impl Decodable for RlpItem {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        Ok(item.clone())
+    }
+}
+
+macro_rules! impl_rlp_int {
+    ($($int:ty),*) => {
+        $(
+            impl Encodable for $int {
+                fn to_rlp(&self) -> RlpItem {
+                    RlpItem::Bytes(BigInt::from(*self).to_signed_bytes_be())
+                }
+            }
+
+            impl Decodable for $int {
+                fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+                    let value = BigInt::from_rlp(item)?;
+                    <$int>::try_from(&value).map_err(|_| IconError::Rlp(format!("{} out of range of {}", value, stringify!($int))))
+                }
+            }
+        )*
+    };
+}
+
+impl_rlp_int!(i8, i16, i32, i64, u16, u32, u64);
+
+impl Encodable for BigInt {
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::Bytes(self.to_signed_bytes_be())
+    }
+}
+
+impl Decodable for BigInt {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        Ok(BigInt::from_signed_bytes_be(item.as_bytes_or_empty()?))
+    }
+}
+
+impl Encodable for bool {
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::Bytes(vec![*self as u8])
+    }
+}
+
+impl Decodable for bool {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        Ok(BigInt::from_rlp(item)? != BigInt::from(0))
+    }
+}
+
+impl Encodable for Vec<u8> {
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::Bytes(self.clone())
+    }
+}
+
+impl Decodable for Vec<u8> {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        Ok(item.as_bytes_or_empty()?.to_vec())
+    }
+}
+
+impl Encodable for String {
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::Bytes(self.as_bytes().to_vec())
+    }
+}
+
+impl Decodable for String {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        String::from_utf8(item.as_bytes_or_empty()?.to_vec()).map_err(|_| IconError::Rlp("invalid utf-8 string".to_string()))
+    }
+}
+
+impl<T: Encodable> Encodable for Vec<T> {
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::List(self.iter().map(Encodable::to_rlp).collect())
+    }
+}
+
+impl<T: Decodable> Decodable for Vec<T> {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        match item {
+            RlpItem::Nil => Ok(Vec::new()),
+            item => item.as_list()?.iter().map(T::from_rlp).collect(),
+        }
+    }
+}
+
+impl<T: Encodable> Encodable for Option<T> {
+    fn to_rlp(&self) -> RlpItem {
+        self.as_ref().map_or(RlpItem::Nil, Encodable::to_rlp)
+    }
+}
+
+impl<T: Decodable> Decodable for Option<T> {
+    fn from_rlp(item: &RlpItem) -> Result<Self, IconError> {
+        match item {
+            RlpItem::Nil => Ok(None),
+            item => T::from_rlp(item).map(Some),
+        }
+    }
+}
+
+// Splits the payload of a string (`offset` 0x80) or list (`offset` 0xc0) from what follows it
+fn split_payload(data: &[u8], offset: u8) -> Result<(&[u8], &[u8]), IconError> {
+    let short = data[0] - offset;
+    let (start, length) = if short <= 55 {
+        (1, short as usize)
+    } else {
+        let size = (short - 55) as usize;
+        let length_bytes = data.get(1..1 + size).ok_or_else(|| IconError::Rlp("truncated length".to_string()))?;
+        let length = length_bytes.iter().try_fold(0usize, |length, byte| {
+            length.checked_mul(256).map(|length| length + *byte as usize)
+        }).ok_or_else(|| IconError::Rlp("length overflow".to_string()))?;
+        (1 + size, length)
+    };
+
+    let end = start.checked_add(length).filter(|end| *end <= data.len())
+        .ok_or_else(|| IconError::Rlp("truncated item".to_string()))?;
+
+    Ok((&data[start..end], &data[end..]))
+}
+
+fn encode_length(length: usize, offset: u8) -> Vec<u8> {
+    if length <= 55 {
+        return vec![offset + length as u8];
+    }
+
+    let bytes = length.to_be_bytes();
+    let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len() - 1);
+    let mut encoded = vec![offset + 55 + (bytes.len() - start) as u8];
+    encoded.extend_from_slice(&bytes[start..]);
+
+    encoded
+}
@@ -0,0 +1,195 @@
+use std::sync::Arc;
+use futures_util::stream::{self, BoxStream};
+use futures_util::{StreamExt, TryStreamExt};
+use tokio::sync::Semaphore;
+use crate::block_header::BlockHeader;
+use crate::error::IconError;
+use crate::event_filter::EventFilter;
+use crate::icon_service::IconService;
+use crate::requests::{GetBlockByHeight, GetBlockHeaderByHeight, GetTransactionResult, Request};
+use crate::responses::{Block, TransactionInfo, TransactionResult};
+
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+type TransactionFilter = Arc<dyn Fn(&TransactionInfo) -> bool + Send + Sync>;
+
+/// A transaction selected by a scan, with its receipt.
+///
+/// `events` are the indexes of the logs matching the event filter, empty without one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScanResult {
+    pub height: u64,
+    pub block_hash: String,
+    pub transaction: TransactionInfo,
+    pub result: TransactionResult,
+    pub events: Vec<usize>,
+}
+
+/// Walks the blocks of a height range, fetching receipts of the selected transactions.
+///
+/// At most `concurrency` requests are in flight at once, across blocks and receipts, results
+/// are still yielded in chain order.
+#[derive(Clone)]
+pub struct Scanner {
+    icon_service: IconService,
+    from_height: u64,
+    to_height: u64,
+    concurrency: usize,
+    // Shared by every request of the scan
+    limit: Arc<Semaphore>,
+    transaction_filter: Option<TransactionFilter>,
+    event_filter: Option<EventFilter>,
+    skip_by_bloom: bool,
+}
+
+impl Scanner {
+    /// Scans from `from_height` to `to_height`, both included.
+    pub fn new(icon_service: &IconService, from_height: u64, to_height: u64) -> Self {
+        Self {
+            icon_service: icon_service.clone(),
+            from_height,
+            to_height,
+            concurrency: DEFAULT_CONCURRENCY,
+            limit: Arc::new(Semaphore::new(DEFAULT_CONCURRENCY)),
+            transaction_filter: None,
+            event_filter: None,
+            skip_by_bloom: false,
+        }
+    }
+
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self.limit = Arc::new(Semaphore::new(self.concurrency));
+        self
+    }
+
+    /// Only fetches the receipts of transactions for which `filter` returns true.
+    pub fn transactions<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&TransactionInfo) -> bool + Send + Sync + 'static,
+    {
+        self.transaction_filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Only yields transactions emitting at least one event matching `filter`.
+    pub fn events(mut self, filter: EventFilter) -> Self {
+        self.event_filter = Some(filter);
+        self
+    }
+
+    /// Skips fetching the receipts of blocks whose logs bloom rules out a match with the event
+    /// filter, at the cost of one header request per block with selected transactions.
+    ///
+    /// Off by default: a bloom computed differently from the node's would silently drop events.
+    pub fn skip_by_bloom(mut self, skip: bool) -> Self {
+        self.skip_by_bloom = skip;
+        self
+    }
+
+    /// Streams the selected transactions in order. The scan stops at the first error, everything
+    /// yielded before it is complete so it can be resumed from the height of the last result.
+    pub fn scan(self) -> BoxStream<'static, Result<ScanResult, IconError>> {
+        if let Some(Err(e)) = self.event_filter.as_ref().map(EventFilter::validate) {
+            return stream::once(async move { Err(e) }).boxed();
+        }
+
+        let scanner = Arc::new(self);
+        let concurrency = scanner.concurrency;
+
+        stream::iter(scanner.from_height..=scanner.to_height)
+            .map(move |height| {
+                let scanner = scanner.clone();
+                async move { scanner.scan_block(height).await }
+            })
+            .buffered(concurrency)
+            .map_ok(|results| stream::iter(results.into_iter().map(Ok)))
+            .try_flatten()
+            .scan(false, |failed, result| {
+                // End the stream after yielding the first error
+                if *failed {
+                    return futures_util::future::ready(None);
+                }
+                *failed = result.is_err();
+                futures_util::future::ready(Some(result))
+            })
+            .boxed()
+    }
+
+    async fn fetch<R: Request>(&self, request: &R) -> Result<R::Response, IconError> {
+        let _permit = self.limit.acquire().await.map_err(|_| IconError::UnexpectedResponse("scan cancelled".to_string()))?;
+
+        self.icon_service.fetch(request).await
+    }
+
+    async fn scan_block(&self, height: u64) -> Result<Vec<ScanResult>, IconError> {
+        let block: Block = self.fetch(&GetBlockByHeight { height: format!("0x{:x}", height) }).await?;
+
+        let transactions: Vec<TransactionInfo> = block.confirmed_transaction_list.iter()
+            .filter(|transaction| self.transaction_filter.as_ref().is_none_or(|filter| filter(transaction)))
+            .cloned()
+            .collect();
+        if transactions.is_empty() || !self.may_match(height).await {
+            return Ok(Vec::new());
+        }
+
+        let results: Vec<ScanResult> = stream::iter(transactions)
+            .map(|transaction| self.scan_transaction(&block, transaction))
+            .buffered(self.concurrency)
+            .try_collect::<Vec<Option<ScanResult>>>()
+            .await?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Ok(results)
+    }
+
+    // Whether the block at `height` may hold events matching the filter, according to the logs
+    // bloom of the next header, which commits to the receipts of this block. Errs on the side of
+    // fetching receipts when the bloom is not available, e.g. at the tip of the chain.
+    async fn may_match(&self, height: u64) -> bool {
+        if !self.skip_by_bloom {
+            return true;
+        }
+        let bloom = match self.event_filter.as_ref().map(EventFilter::bloom) {
+            Some(Ok(bloom)) => bloom,
+            _ => return true,
+        };
+        let header = match self.fetch(&GetBlockHeaderByHeight { height: format!("0x{:x}", height + 1) }).await {
+            Ok(header) => header,
+            Err(_) => return true,
+        };
+
+        BlockHeader::from_base64(&header).map_or(true, |header| header.logs_bloom.contains_bloom(&bloom))
+    }
+
+    async fn scan_transaction(&self, block: &Block, transaction: TransactionInfo) -> Result<Option<ScanResult>, IconError> {
+        // Transactions of old blocks are listed without the `0x` prefix
+        let tx_hash = if transaction.tx_hash.starts_with("0x") {
+            transaction.tx_hash.clone()
+        } else {
+            format!("0x{}", transaction.tx_hash)
+        };
+        let result = self.fetch(&GetTransactionResult { tx_hash }).await?;
+
+        let events = match &self.event_filter {
+            Some(filter) => {
+                let events = filter.matching_events(&result.event_logs);
+                if events.is_empty() {
+                    return Ok(None);
+                }
+                events
+            },
+            None => Vec::new(),
+        };
+
+        Ok(Some(ScanResult {
+            height: block.height,
+            block_hash: block.block_hash.clone(),
+            transaction,
+            result,
+            events,
+        }))
+    }
+}
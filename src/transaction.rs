@@ -1,9 +1,10 @@
 use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
+use serde_json::{json, Map, Value};
 
-use thiserror::Error;
+use crate::error::IconError;
 use crate::icon_service::IconService;
+use crate::utils::serializer::Serializer;
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct Transaction {
@@ -11,19 +12,48 @@ pub struct Transaction {
     pub(crate) data: Value,
 }
 
-#[derive(Error, Debug)]
-pub enum MyError {
-    #[error("request failed")]
-    Request(#[from] reqwest::Error),
+/// The params of a signed `icx_sendTransaction`, ready to be stored and broadcast later.
+///
+/// Serializes to the same JSON object that is sent to the node, so it can be written to a file
+/// on an offline machine and submitted with `IconService::send_raw_transaction`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SignedTransaction {
+    pub(crate) params: Map<String, Value>,
+}
+
+impl SignedTransaction {
+    pub fn params(&self) -> &Map<String, Value> {
+        &self.params
+    }
+
+    /// The transaction hash, i.e. the SHA3-256 of the serialized params that were signed.
+    /// This is the hash the node returns from `icx_sendTransaction`, known before broadcasting.
+    pub fn tx_hash(&self) -> String {
+        let mut params = self.params.clone();
+        params.remove("signature");
 
-    #[error("unexpected response: {0}")]
-    UnexpectedResponse(String),
+        format!("0x{}", Serializer::serialize_transaction(&Value::Object(params), true))
+    }
 }
 
+#[deprecated(note = "use `error::IconError` instead")]
+pub type MyError = IconError;
+
 impl Transaction {
     pub fn new(icon_service: &IconService) -> Self {
         Self {
-            icon_service_url: format!("{}", icon_service.icon_service_url),
+            icon_service_url: icon_service.icon_service_url.to_string(),
+            data: json!({
+                "jsonrpc": "2.0",
+                "id": 1234
+            }),
+        }
+    }
+
+    pub fn new_offline() -> Self {
+        Self {
+            icon_service_url: String::new(),
             data: json!({
                 "jsonrpc": "2.0",
                 "id": 1234
@@ -31,7 +61,7 @@ impl Transaction {
         }
     }
 
-    pub async fn send(self) -> Result<Value, MyError> {
+    pub async fn send(self) -> Result<Value, IconError> {
         let client = Client::new();
         let url = self.icon_service_url;
         let data = self.data;
@@ -45,7 +75,7 @@ impl Transaction {
             StatusCode::OK => Ok(res.json().await?),
             _ => {
                 let error_message = res.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-                Err(MyError::UnexpectedResponse(error_message))
+                Err(IconError::UnexpectedResponse(error_message))
             },
         }
     }
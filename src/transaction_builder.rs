@@ -7,20 +7,45 @@ use serde_json::{json, Map, Value};
 use std::str::FromStr;
 use base64::{Engine as _, engine::{general_purpose as base64_encoder}};
 use hex::FromHex;
+use crate::error::IconError;
 use crate::icon_service::IconService;
-use crate::transaction::Transaction;
+use crate::transaction::{SignedTransaction, Transaction};
 use crate::utils::helpers::icx_to_hex;
 use crate::utils::serializer::Serializer;
+use crate::utils::transaction_types::TransactionType;
+
+const REQUIRED_TRANSACTION_FIELDS: [&str; 6] = ["from", "to", "nid", "stepLimit", "timestamp", "version"];
+
+// Fields holding hex encoded integers
+const HEX_TRANSACTION_FIELDS: [&str; 6] = ["value", "nid", "nonce", "stepLimit", "timestamp", "version"];
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct TransactionBuilder {
     transaction: Transaction,
+    // Validation errors collected while building, reported by `build`
+    #[serde(skip)]
+    errors: Vec<String>,
+    // Network id of the service, used when none is set
+    #[serde(skip)]
+    default_nid: Option<String>,
 }
 
 impl TransactionBuilder {
     pub fn new(icon_service: &IconService) -> Self {
         Self {
             transaction: Transaction::new(icon_service),
+            errors: Vec::new(),
+            default_nid: icon_service.nid.clone(),
+        }
+    }
+
+    /// Creates a builder that is not tied to a node, for signing transactions offline.
+    /// Finish it with `build_signed` and broadcast the result with `IconService::send_raw_transaction`.
+    pub fn new_offline() -> Self {
+        Self {
+            transaction: Transaction::new_offline(),
+            errors: Vec::new(),
+            default_nid: None,
         }
     }
 
@@ -35,8 +60,17 @@ impl TransactionBuilder {
 
     pub fn set_params(mut self, params: &Map<String, Value>) -> Self {
         // Ensure `data` has a "params" object; create it if not
-        let data_obj = self.transaction.data.as_object_mut().expect("data is not an object");
-        let params_obj = data_obj.entry("params").or_insert_with(|| json!({})).as_object_mut().unwrap();
+        let params_obj = match self.transaction.data.as_object_mut() {
+            Some(data_obj) => data_obj.entry("params").or_insert_with(|| json!({})).as_object_mut(),
+            None => None,
+        };
+        let params_obj = match params_obj {
+            Some(params_obj) => params_obj,
+            None => {
+                self.errors.push("transaction data is not an object".to_string());
+                return self;
+            }
+        };
 
         // Insert or update the given parameters
         for (key, value) in params {
@@ -88,16 +122,19 @@ impl TransactionBuilder {
         self.set_params(&params)
     }
 
-    pub fn value(self, value: &str) -> Self {
+    pub fn value(mut self, value: &str) -> Self {
         let mut params = Map::new();
         let mut parsed_value = value.to_string();
 
         if !parsed_value.starts_with("0x") {
-            match icx_to_hex(Decimal::from_str(value).expect("Invalid value")) {
+            match Decimal::from_str(value).ok().and_then(icx_to_hex) {
                 Some(v) => {
                     parsed_value = v;
                 }
-                None => panic!("Failed to convert value to hex"),
+                None => {
+                    self.errors.push(format!("invalid value: {}", value));
+                    return self;
+                }
             }
         }
 
@@ -121,6 +158,14 @@ impl TransactionBuilder {
         self.set_params(&params)
     }
 
+    /// Sets `nid` if given, otherwise leaves it to the service's network id.
+    pub fn nid_or_default(self, nid: Option<&str>) -> Self {
+        match nid {
+            Some(nid) => self.nid(nid),
+            None => self,
+        }
+    }
+
     pub fn nonce(self, nonce: &str) -> Self {
         let mut params = Map::new();
         params.insert("nonce".to_string(), json!(nonce));
@@ -163,21 +208,42 @@ impl TransactionBuilder {
         self.set_params(&params)
     }
 
-    pub fn sign(self, private_key: &str) -> Self {
+    pub fn sign(mut self, private_key: &str) -> Self {
+        let secret_key = match decode(private_key).ok().and_then(|key| SecretKey::from_slice(&key).ok()) {
+            Some(secret_key) => secret_key,
+            None => {
+                self.errors.push("invalid private key".to_string());
+                return self;
+            }
+        };
+        // Don't sign a transaction `build` is going to reject anyway
+        if !self.errors.is_empty() {
+            return self;
+        }
+        self = self.fill_default_nid();
         let serialized_transaction = Serializer::serialize_transaction(&self.transaction.data["params"], true);
-        let serialized_transaction_bytes = Vec::from_hex(serialized_transaction).expect("Invalid hex string");
+        let serialized_transaction_bytes = match Vec::from_hex(serialized_transaction) {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                self.errors.push("failed to serialize transaction".to_string());
+                return self;
+            }
+        };
 
         let secp = Secp256k1::new();
-        let secret_key = SecretKey::from_slice(&decode(private_key).expect("Invalid private key")).expect("Failed to create secret key");
-
-        let message = Message::from_digest_slice(serialized_transaction_bytes.as_slice()).expect("Failed to create message");
+        let message = match Message::from_digest_slice(serialized_transaction_bytes.as_slice()) {
+            Ok(message) => message,
+            Err(_) => {
+                self.errors.push("failed to create message".to_string());
+                return self;
+            }
+        };
         let sig = secp.sign_ecdsa_recoverable(&message, &secret_key);
 
         let (rec_id, sig_bytes) = sig.serialize_compact();
         // Concatenate r, s, and recovery ID
-        let signature = format!("{}{:02x}", encode(sig_bytes), rec_id.to_i32());
-
-        let signature_bytes = decode(signature).expect("Failed to decode hex");
+        let mut signature_bytes = sig_bytes.to_vec();
+        signature_bytes.push(rec_id.to_i32() as u8);
         let transaction_signature = base64_encoder::STANDARD.encode(signature_bytes);
 
         let mut params = Map::new();
@@ -186,7 +252,87 @@ impl TransactionBuilder {
         self.set_params(&params)
     }
 
-    pub fn build(self) -> Transaction {
-        self.transaction
+    /// Returns the transaction, or every validation error collected while building it.
+    /// Transactions to send are also checked for the fields required by their `dataType`.
+    pub fn build(mut self) -> Result<Transaction, IconError> {
+        self = self.fill_default_nid();
+        let is_send = self.is_send();
+        let mut errors = self.errors;
+        if is_send {
+            errors.extend(Self::check_fields(&self.transaction.data["params"], false));
+        }
+
+        if !errors.is_empty() {
+            return Err(IconError::InvalidTransaction(errors));
+        }
+
+        Ok(self.transaction)
+    }
+
+    pub fn build_signed(self) -> Result<SignedTransaction, IconError> {
+        let mut errors = self.errors;
+        errors.extend(Self::check_fields(&self.transaction.data["params"], true));
+
+        if !errors.is_empty() {
+            return Err(IconError::InvalidTransaction(errors));
+        }
+
+        let params = self.transaction.data["params"].as_object().cloned().unwrap_or_default();
+
+        Ok(SignedTransaction { params })
+    }
+
+    fn is_send(&self) -> bool {
+        let method = &self.transaction.data["method"];
+        *method == TransactionType::SendTransaction.as_str() || *method == TransactionType::SendTransactionAndWait.as_str()
+    }
+
+    // Sets the service's network id on transactions sent without one
+    fn fill_default_nid(self) -> Self {
+        match &self.default_nid {
+            Some(nid) if self.is_send() && self.transaction.data["params"].get("nid").is_none() => {
+                let nid = nid.clone();
+                self.nid(&nid)
+            },
+            _ => self,
+        }
     }
-}
\ No newline at end of file
+
+    fn check_fields(params: &Value, signed: bool) -> Vec<String> {
+        let mut required = REQUIRED_TRANSACTION_FIELDS.to_vec();
+        if signed {
+            required.push("signature");
+        }
+
+        let mut errors: Vec<String> = required.into_iter()
+            .filter(|field| params.get(field).is_none())
+            .map(|field| format!("missing {}", field))
+            .collect();
+
+        // What `data` must hold for each `dataType`
+        let data = &params["data"];
+        let data_fields: &[&str] = match params["dataType"].as_str() {
+            Some("call") => &["method"],
+            Some("deploy") => &["contentType", "content"],
+            _ => &[],
+        };
+        if params.get("dataType").is_some() && data.is_null() {
+            errors.push("missing data".to_string());
+        } else {
+            errors.extend(data_fields.iter()
+                .filter(|field| data.get(field).is_none())
+                .map(|field| format!("missing data.{}", field)));
+        }
+
+        errors.extend(HEX_TRANSACTION_FIELDS.iter()
+            .filter_map(|field| params.get(field).map(|value| (field, value)))
+            .filter(|(_, value)| !value.as_str().is_some_and(is_hex_int))
+            .map(|(field, value)| format!("invalid {}: {}", field, value.as_str().map_or(value.to_string(), str::to_string))));
+
+        errors
+    }
+}
+
+fn is_hex_int(value: &str) -> bool {
+    value.strip_prefix("0x").is_some_and(|digits| !digits.is_empty() && digits.chars().all(|c| c.is_ascii_hexdigit()))
+}
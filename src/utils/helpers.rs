@@ -1,7 +1,9 @@
+use base64::{Engine as _, engine::general_purpose as base64_encoder};
 use num_bigint::BigInt;
 use num_traits::{Num, ToPrimitive};
 use rust_decimal::Decimal;
 use std::str::FromStr;
+use crate::error::IconError;
 
 pub fn hex_to_icx(value: &str) -> Option<Decimal> {
     // Strip the "0x" prefix if present and parse the remaining hex string into a BigInt
@@ -22,8 +24,13 @@ pub fn icx_to_hex(value: Decimal) -> Option<String> {
     let multiplier = Decimal::from_str(&10u128.pow(18).to_string()).ok()?;
     // Perform the multiplication to adjust for decimal places
     let result_decimal = value * multiplier;
-    let result_bigint = result_decimal.to_i128().and_then(|val| Some(BigInt::from(val)))?;
+    let result_bigint = result_decimal.to_i128().map(BigInt::from)?;
 
     // Convert BigInt to hexadecimal string and prefix with "0x"
     Some(format!("0x{}", result_bigint.to_str_radix(16)))
 }
+
+pub(crate) fn decode_base64(value: &str) -> Result<Vec<u8>, IconError> {
+    base64_encoder::STANDARD.decode(value)
+        .map_err(|_| IconError::UnexpectedResponse(format!("invalid base64: {}", value)))
+}
@@ -1,3 +1,3 @@
 pub mod helpers;
 pub(crate) mod serializer;
-pub(crate) mod transaction_types;
+pub mod transaction_types;
@@ -12,7 +12,7 @@ impl Serializer {
         let result = format!("icx_sendTransaction.{}", result_string_replaced);
 
         if hashed {
-            format!("{}", encode(Sha3_256::digest(result.as_bytes())))
+            encode(Sha3_256::digest(result.as_bytes()))
         } else {
             result
         }
@@ -1,25 +1,67 @@
-pub(crate) enum TransactionType {
+/// The methods of the ICON JSON-RPC v3 API, including the BTP extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionType {
     LastBlock,
     BlockByHeight,
     BlockByHash,
     TransactionResult,
+    WaitTransactionResult,
     TransactionByHash,
     Balance,
+    TotalSupply,
+    ScoreApi,
+    ScoreStatus,
     SendTransaction,
+    SendTransactionAndWait,
     Call,
+    DataByHash,
+    BlockHeaderByHeight,
+    VotesByHeight,
+    ProofForResult,
+    ProofForEvents,
+    NetworkInfo,
+    BtpNetworkInfo,
+    BtpNetworkTypeInfo,
+    BtpMessages,
+    BtpHeader,
+    BtpProof,
+    BtpSourceInformation,
+    Trace,
+    DebugAccount,
+    EstimateStep,
 }
 
 impl TransactionType {
-    pub(crate) fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &'static str {
         match self {
             TransactionType::LastBlock => "icx_getLastBlock",
             TransactionType::BlockByHeight => "icx_getBlockByHeight",
             TransactionType::BlockByHash => "icx_getBlockByHash",
             TransactionType::TransactionResult => "icx_getTransactionResult",
+            TransactionType::WaitTransactionResult => "icx_waitTransactionResult",
             TransactionType::TransactionByHash => "icx_getTransactionByHash",
             TransactionType::Balance => "icx_getBalance",
+            TransactionType::TotalSupply => "icx_getTotalSupply",
+            TransactionType::ScoreApi => "icx_getScoreApi",
+            TransactionType::ScoreStatus => "icx_getScoreStatus",
             TransactionType::SendTransaction => "icx_sendTransaction",
+            TransactionType::SendTransactionAndWait => "icx_sendTransactionAndWait",
             TransactionType::Call => "icx_call",
+            TransactionType::DataByHash => "icx_getDataByHash",
+            TransactionType::BlockHeaderByHeight => "icx_getBlockHeaderByHeight",
+            TransactionType::VotesByHeight => "icx_getVotesByHeight",
+            TransactionType::ProofForResult => "icx_getProofForResult",
+            TransactionType::ProofForEvents => "icx_getProofForEvents",
+            TransactionType::NetworkInfo => "icx_getNetworkInfo",
+            TransactionType::BtpNetworkInfo => "btp_getNetworkInfo",
+            TransactionType::BtpNetworkTypeInfo => "btp_getNetworkTypeInfo",
+            TransactionType::BtpMessages => "btp_getMessages",
+            TransactionType::BtpHeader => "btp_getHeader",
+            TransactionType::BtpProof => "btp_getProof",
+            TransactionType::BtpSourceInformation => "btp_getSourceInformation",
+            TransactionType::Trace => "debug_getTrace",
+            TransactionType::DebugAccount => "debug_getAccount",
+            TransactionType::EstimateStep => "debug_estimateStep",
         }
     }
 }
@@ -1,7 +1,10 @@
 use secp256k1::rand::rngs::OsRng;
-use secp256k1::{Secp256k1, SecretKey, PublicKey};
+use secp256k1::{Message, Secp256k1, SecretKey, PublicKey};
+use secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
 use sha3::{Digest, Sha3_256};
 use hex::{encode, decode};
+use base64::{Engine as _, engine::{general_purpose as base64_encoder}};
+use crate::error::IconError;
 
 pub struct Wallet {
     private_key: String,
@@ -21,11 +24,11 @@ impl Wallet {
             },
             None => {
                 // Generate a new keypair
-                let (private_key, public_key) = secp.generate_keypair(&mut OsRng::default());
+                let (private_key, public_key) = secp.generate_keypair(&mut OsRng);
                 (private_key, public_key)
             },
         };
-        let public_key_hex = encode(public_key.serialize_uncompressed()[1..].to_vec());
+        let public_key_hex = encode(&public_key.serialize_uncompressed()[1..]);
 
         let public_address = Wallet::pub_key_to_address(&public_key_hex);
 
@@ -37,8 +40,8 @@ impl Wallet {
     }
 
     fn pub_key_to_address(public_key: &str) -> String {
-        let digest = Sha3_256::digest(&decode(public_key).expect("Invalid hex in public key"));
-        let hex_digest = encode(&digest);
+        let digest = Sha3_256::digest(decode(public_key).expect("Invalid hex in public key"));
+        let hex_digest = encode(digest);
         if hex_digest.len() >= 40 {
             // Ensure the string is long enough before slicing to avoid panics
             format!("hx{}", &hex_digest[hex_digest.len() - 40..])
@@ -47,6 +50,27 @@ impl Wallet {
         }
     }
 
+    /// Signs an arbitrary message, returning the base64 encoded recoverable signature.
+    ///
+    /// The message is hashed with SHA3-256 and signed the same way as transactions,
+    /// so the output has the `r || s || recovery_id` layout ICON wallets expect.
+    pub fn sign_message(&self, message: &[u8]) -> Result<String, IconError> {
+        let secp = Secp256k1::new();
+        let secret_key = decode(&self.private_key).ok()
+            .and_then(|key| SecretKey::from_slice(&key).ok())
+            .ok_or(IconError::InvalidPrivateKey)?;
+
+        let message = Message::from_digest(Sha3_256::digest(message).into());
+        let sig = secp.sign_ecdsa_recoverable(&message, &secret_key);
+
+        let (rec_id, sig_bytes) = sig.serialize_compact();
+        // Concatenate r, s, and recovery ID
+        let mut signature_bytes = sig_bytes.to_vec();
+        signature_bytes.push(rec_id.to_i32() as u8);
+
+        Ok(base64_encoder::STANDARD.encode(signature_bytes))
+    }
+
     // Accessor methods
     pub fn get_private_key(&self) -> String {
         self.private_key.clone()
@@ -60,3 +84,32 @@ impl Wallet {
         self.public_address.clone()
     }
 }
+
+/// Verifies a signature produced by [`Wallet::sign_message`].
+///
+/// Recovers the signer's public key from the signature and checks that it maps to `address`.
+/// Malformed signatures are reported as not matching.
+pub fn verify_message(address: &str, message: &[u8], signature: &str) -> bool {
+    match base64_encoder::STANDARD.decode(signature) {
+        Ok(signature_bytes) => recover_address(message, &signature_bytes).is_some_and(|signer| signer == address),
+        Err(_) => false,
+    }
+}
+
+/// Recovers the address that signed the SHA3-256 hash of `message`, given the raw
+/// 65 byte `r || s || recovery_id` signature.
+pub fn recover_address(message: &[u8], signature: &[u8]) -> Option<String> {
+    if signature.len() != 65 {
+        return None;
+    }
+
+    let rec_id = RecoveryId::from_i32(signature[64] as i32).ok()?;
+    let sig = RecoverableSignature::from_compact(&signature[..64], rec_id).ok()?;
+
+    let secp = Secp256k1::new();
+    let message = Message::from_digest_slice(&Sha3_256::digest(message)).ok()?;
+    let public_key = secp.recover_ecdsa(&message, &sig).ok()?;
+
+    let public_key_hex = encode(&public_key.serialize_uncompressed()[1..]);
+    Some(Wallet::pub_key_to_address(&public_key_hex))
+}
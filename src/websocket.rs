@@ -0,0 +1,259 @@
+use std::time::Duration;
+use futures_util::stream::{self, BoxStream};
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+use crate::btp::{BtpHeader, BtpNotification};
+use crate::utils::helpers::decode_base64;
+use crate::error::IconError;
+use crate::event_filter::EventFilter;
+use crate::responses::EventLog;
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+const MIN_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// Blocks between progress notifications of event subscriptions.
+pub const PROGRESS_INTERVAL: u64 = 100;
+
+/// A new block, sent by the `/block` websocket endpoint.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BlockNotification {
+    pub hash: String,
+    pub height: String,
+}
+
+/// A transaction emitting events matching the filter, sent by the `/event` websocket endpoint.
+///
+/// `events` are the indexes of the matching logs within the transaction's `logs`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EventNotification {
+    pub hash: String,
+    pub height: String,
+    pub index: String,
+    pub events: Vec<String>,
+    #[serde(default)]
+    pub logs: Vec<EventLog>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventMessage {
+    Event(EventNotification),
+    /// No matching event up to this height, sent every `PROGRESS_INTERVAL` blocks.
+    Progress(u64),
+}
+
+enum ConnectError {
+    // Worth retrying
+    Transport,
+    // The node refused the request, retrying would not help
+    Rejected(IconError),
+}
+
+/// Turns the JSON-RPC URL into the websocket URL of `endpoint`, e.g.
+/// `https://api.icon.community/api/v3` -> `wss://api.icon.community/api/v3/icon_dex/block`.
+pub(crate) fn websocket_url(icon_service_url: &str, endpoint: &str) -> String {
+    let url = icon_service_url.trim_end_matches('/');
+    let url = if let Some(rest) = url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        url.to_string()
+    };
+
+    if url.ends_with("/api/v3") {
+        format!("{}/icon_dex/{}", url, endpoint)
+    } else {
+        format!("{}/{}", url, endpoint)
+    }
+}
+
+pub(crate) fn subscribe_blocks(url: String, from_height: u64) -> BoxStream<'static, Result<BlockNotification, IconError>> {
+    subscribe(
+        url,
+        from_height,
+        |height| json!({ "height": format!("0x{:x}", height) }),
+        |value, next_height| {
+            let notification: BlockNotification = serde_json::from_value(value)?;
+            *next_height = parse_hex(&notification.height)? + 1;
+
+            Ok(Some(notification))
+        },
+    )
+}
+
+pub(crate) fn subscribe_events(url: String, filter: EventFilter, from_height: u64) -> BoxStream<'static, Result<EventMessage, IconError>> {
+    // Resuming restarts at the block of the last event, so events already delivered are skipped
+    let mut last_event: Option<(u64, u64)> = None;
+
+    subscribe(
+        url,
+        from_height,
+        move |height| {
+            let mut request = json!(filter);
+            request["height"] = json!(format!("0x{:x}", height));
+            request["logs"] = json!("0x1");
+            request["progressInterval"] = json!(format!("0x{:x}", PROGRESS_INTERVAL));
+            request
+        },
+        move |value, next_height| {
+            if let Some(progress) = value.get("progress") {
+                let height = parse_hex(progress.as_str().unwrap_or_default())?;
+                *next_height = height;
+
+                return Ok(Some(EventMessage::Progress(height)));
+            }
+
+            let notification: EventNotification = serde_json::from_value(value)?;
+            let position = (parse_hex(&notification.height)?, parse_hex(&notification.index)?);
+            if last_event.is_some_and(|last| position <= last) {
+                return Ok(None);
+            }
+            last_event = Some(position);
+            *next_height = position.0;
+
+            Ok(Some(EventMessage::Event(notification)))
+        },
+    )
+}
+
+pub(crate) fn subscribe_btp(url: String, network_id: u64, proof: bool, from_height: u64) -> BoxStream<'static, Result<BtpNotification, IconError>> {
+    subscribe(
+        url,
+        from_height,
+        move |height| json!({
+            "height": format!("0x{:x}", height),
+            "networkID": format!("0x{:x}", network_id),
+            "proofFlag": if proof { "0x1" } else { "0x0" },
+        }),
+        |value, next_height| {
+            let header = BtpHeader::from_base64(value["header"].as_str().unwrap_or_default())?;
+            let proof = match value["proof"].as_str() {
+                Some(proof) if !proof.is_empty() => Some(decode_base64(proof)?),
+                _ => None,
+            };
+            *next_height = header.main_height + 1;
+
+            Ok(Some(BtpNotification { header, proof }))
+        },
+    )
+}
+
+struct Subscription<R, H> {
+    url: String,
+    next_height: u64,
+    request: R,
+    handler: H,
+    socket: Option<Socket>,
+    delay: Duration,
+    done: bool,
+}
+
+/// Streams the notifications of a websocket endpoint, reconnecting from `next_height` whenever
+/// the connection drops. `handler` decodes a message and moves `next_height` forward.
+fn subscribe<T, R, H>(url: String, from_height: u64, request: R, handler: H) -> BoxStream<'static, Result<T, IconError>>
+where
+    T: Send + 'static,
+    R: Fn(u64) -> Value + Send + 'static,
+    H: FnMut(Value, &mut u64) -> Result<Option<T>, IconError> + Send + 'static,
+{
+    let subscription = Subscription {
+        url,
+        next_height: from_height,
+        request,
+        handler,
+        socket: None,
+        delay: MIN_RECONNECT_DELAY,
+        done: false,
+    };
+
+    stream::unfold(subscription, |mut subscription| async move {
+        if subscription.done {
+            return None;
+        }
+
+        loop {
+            if subscription.socket.is_none() {
+                let request = (subscription.request)(subscription.next_height);
+                match connect(&subscription.url, request).await {
+                    Ok(socket) => {
+                        subscription.socket = Some(socket);
+                        subscription.delay = MIN_RECONNECT_DELAY;
+                    },
+                    Err(ConnectError::Rejected(e)) => {
+                        subscription.done = true;
+                        return Some((Err(e), subscription));
+                    },
+                    Err(ConnectError::Transport) => {
+                        subscription.backoff().await;
+                        continue;
+                    },
+                }
+            }
+
+            let message = match subscription.socket.as_mut() {
+                Some(socket) => socket.next().await,
+                None => continue,
+            };
+
+            match message {
+                Some(Ok(Message::Text(text))) => {
+                    let value: Value = match serde_json::from_str(&text) {
+                        Ok(value) => value,
+                        Err(e) => return Some((Err(e.into()), subscription)),
+                    };
+                    match (subscription.handler)(value, &mut subscription.next_height) {
+                        Ok(Some(item)) => return Some((Ok(item), subscription)),
+                        Ok(None) => continue,
+                        Err(e) => return Some((Err(e), subscription)),
+                    }
+                },
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                    subscription.socket = None;
+                    subscription.backoff().await;
+                },
+                Some(Ok(_)) => continue,
+            }
+        }
+    }).boxed()
+}
+
+impl<R, H> Subscription<R, H> {
+    async fn backoff(&mut self) {
+        tokio::time::sleep(self.delay).await;
+        self.delay = (self.delay * 2).min(MAX_RECONNECT_DELAY);
+    }
+}
+
+async fn connect(url: &str, request: Value) -> Result<Socket, ConnectError> {
+    let (mut socket, _) = connect_async(url).await.map_err(|_| ConnectError::Transport)?;
+    socket.send(Message::Text(request.to_string())).await.map_err(|_| ConnectError::Transport)?;
+
+    // The node acknowledges the request with `{"code": 0}` before sending notifications
+    loop {
+        match socket.next().await {
+            Some(Ok(Message::Text(text))) => {
+                let ack: Value = serde_json::from_str(&text).map_err(|e| ConnectError::Rejected(e.into()))?;
+                return match ack["code"].as_i64() {
+                    Some(0) => Ok(socket),
+                    _ => Err(ConnectError::Rejected(IconError::Rpc {
+                        code: ack["code"].as_i64().unwrap_or_default(),
+                        message: ack["message"].as_str().unwrap_or_default().to_string(),
+                    })),
+                };
+            },
+            Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return Err(ConnectError::Transport),
+            Some(Ok(_)) => continue,
+        }
+    }
+}
+
+fn parse_hex(value: &str) -> Result<u64, IconError> {
+    u64::from_str_radix(value.strip_prefix("0x").unwrap_or(value), 16)
+        .map_err(|_| IconError::UnexpectedResponse(format!("invalid height: {}", value)))
+}
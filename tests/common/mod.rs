@@ -0,0 +1,106 @@
+// Each test binary uses its own subset of the helpers
+#![allow(dead_code)]
+
+use std::sync::Arc;
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::accept_async;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Serves JSON-RPC over HTTP on a local port, answering each request with `handler(method, params)`,
+/// and returns the `/api/v3` URL to give to `IconService`.
+pub async fn serve_rpc<F>(handler: F) -> String
+where
+    F: Fn(&str, &Value) -> Result<Value, (i64, String)> + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/api/v3", listener.local_addr().unwrap());
+    let handler = Arc::new(handler);
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(serve_connection(stream, handler.clone()));
+        }
+    });
+
+    url
+}
+
+async fn serve_connection<F>(mut stream: TcpStream, handler: Arc<F>)
+where
+    F: Fn(&str, &Value) -> Result<Value, (i64, String)> + Send + Sync + 'static,
+{
+    let mut buffer = Vec::new();
+    loop {
+        // Headers, then a body of Content-Length bytes
+        let header_end = loop {
+            if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break position + 4;
+            }
+            if !read_more(&mut stream, &mut buffer).await {
+                return;
+            }
+        };
+        let headers = String::from_utf8_lossy(&buffer[..header_end]).to_lowercase();
+        let content_length: usize = headers.lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .map(|value| value.trim().parse().unwrap())
+            .unwrap_or_default();
+        while buffer.len() < header_end + content_length {
+            if !read_more(&mut stream, &mut buffer).await {
+                return;
+            }
+        }
+
+        let request: Value = serde_json::from_slice(&buffer[header_end..header_end + content_length]).unwrap();
+        buffer.drain(..header_end + content_length);
+
+        let method = request["method"].as_str().unwrap_or_default();
+        let response = match handler(method, &request["params"]) {
+            Ok(result) => json!({"jsonrpc": "2.0", "id": request["id"], "result": result}),
+            Err((code, message)) => json!({"jsonrpc": "2.0", "id": request["id"], "error": {"code": code, "message": message}}),
+        };
+        let body = response.to_string();
+        let reply = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+        if stream.write_all(reply.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+async fn read_more(stream: &mut TcpStream, buffer: &mut Vec<u8>) -> bool {
+    let mut chunk = [0u8; 4096];
+    match stream.read(&mut chunk).await {
+        Ok(0) | Err(_) => false,
+        Ok(n) => {
+            buffer.extend_from_slice(&chunk[..n]);
+            true
+        },
+    }
+}
+
+// Serves one connection per entry of `sessions`: checks the request, acknowledges it,
+// sends the notifications and drops the connection
+pub async fn serve_websocket(listener: TcpListener, sessions: Vec<(Value, Value, Vec<Value>)>) {
+    for (expected_request, ack, notifications) in sessions {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut socket = accept_async(stream).await.unwrap();
+
+        let request = match socket.next().await {
+            Some(Ok(Message::Text(text))) => serde_json::from_str::<Value>(&text).unwrap(),
+            other => panic!("Unexpected message: {:?}", other),
+        };
+        for (key, value) in expected_request.as_object().unwrap() {
+            assert_eq!(&request[key], value);
+        }
+
+        socket.send(Message::Text(ack.to_string())).await.unwrap();
+        for notification in notifications {
+            socket.send(Message::Text(notification.to_string())).await.unwrap();
+        }
+        socket.close(None).await.unwrap();
+    }
+}
@@ -0,0 +1,102 @@
+// Generated by icon_sdk::codegen. Do not edit.
+
+pub struct Irc2Token {
+    contract: icon_sdk::contract::Contract,
+}
+
+impl Irc2Token {
+    const SCORE_API: &'static str = r###"[{"type":"function","name":"name","inputs":[],"outputs":[{"name":"","type":"str"}],"readonly":"0x1"},{"type":"function","name":"symbol","inputs":[],"outputs":[{"name":"","type":"str"}],"readonly":"0x1"},{"type":"function","name":"decimals","inputs":[],"outputs":[{"name":"","type":"int"}],"readonly":"0x1"},{"type":"function","name":"totalSupply","inputs":[],"outputs":[{"name":"","type":"int"}],"readonly":"0x1"},{"type":"function","name":"balanceOf","inputs":[{"name":"_owner","type":"Address"}],"outputs":[{"name":"","type":"int"}],"readonly":"0x1"},{"type":"function","name":"transfer","inputs":[{"name":"_to","type":"Address"},{"name":"_value","type":"int"},{"name":"_data","type":"bytes","default":null}],"outputs":[]},{"type":"function","name":"mintBatch","inputs":[{"name":"_owners","type":"[]Address"}],"outputs":[],"payable":"0x1"},{"type":"fallback","name":"fallback","inputs":[],"outputs":[],"payable":"0x1"},{"type":"eventlog","name":"Transfer","inputs":[{"name":"_from","type":"Address","indexed":"0x1"},{"name":"_to","type":"Address","indexed":"0x1"},{"name":"_value","type":"int","indexed":"0x1"},{"name":"_data","type":"bytes"}],"outputs":[]}]"###;
+
+    pub fn new(icon_service: icon_sdk::icon_service::IconService, contract_address: &str) -> Self {
+        let score_api = icon_sdk::responses::ScoreApi::from_json(Self::SCORE_API).expect("Invalid SCORE API");
+
+        Self {
+            contract: icon_sdk::contract::Contract::with_api(icon_service, contract_address, score_api),
+        }
+    }
+
+    pub fn contract(&self) -> &icon_sdk::contract::Contract {
+        &self.contract
+    }
+
+    pub async fn name(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let result = self.contract.call("name", &[]).await?;
+
+        Ok(icon_sdk::contract::FromScoreValue::from_score_value(result)?)
+    }
+
+    pub async fn symbol(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let result = self.contract.call("symbol", &[]).await?;
+
+        Ok(icon_sdk::contract::FromScoreValue::from_score_value(result)?)
+    }
+
+    pub async fn decimals(&self) -> Result<icon_sdk::num_bigint::BigInt, Box<dyn std::error::Error>> {
+        let result = self.contract.call("decimals", &[]).await?;
+
+        Ok(icon_sdk::contract::FromScoreValue::from_score_value(result)?)
+    }
+
+    pub async fn total_supply(&self) -> Result<icon_sdk::num_bigint::BigInt, Box<dyn std::error::Error>> {
+        let result = self.contract.call("totalSupply", &[]).await?;
+
+        Ok(icon_sdk::contract::FromScoreValue::from_score_value(result)?)
+    }
+
+    pub async fn balance_of(&self, owner: &str) -> Result<icon_sdk::num_bigint::BigInt, Box<dyn std::error::Error>> {
+        let params: Vec<(&str, icon_sdk::contract::ScoreValue)> = vec![
+            ("_owner", icon_sdk::contract::ScoreValue::address(owner)),
+        ];
+        let result = self.contract.call("balanceOf", &params).await?;
+
+        Ok(icon_sdk::contract::FromScoreValue::from_score_value(result)?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn transfer(&self, wallet: &icon_sdk::wallet::Wallet, to: &str, value: icon_sdk::num_bigint::BigInt, data: Option<&[u8]>, version: &str, nid: Option<&str>, nonce: &str, step_limit: &str) -> Result<icon_sdk::serde_json::Value, Box<dyn std::error::Error>> {
+        let mut params: Vec<(&str, icon_sdk::contract::ScoreValue)> = vec![
+            ("_to", icon_sdk::contract::ScoreValue::address(to)),
+            ("_value", icon_sdk::contract::ScoreValue::from(value)),
+        ];
+        if let Some(data) = data {
+            params.push(("_data", icon_sdk::contract::ScoreValue::from(data)));
+        }
+        self.contract.send(wallet, "transfer", &params, None, version, nid, nonce, step_limit).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn mint_batch(&self, wallet: &icon_sdk::wallet::Wallet, owners: Vec<String>, icx_value: &str, version: &str, nid: Option<&str>, nonce: &str, step_limit: &str) -> Result<icon_sdk::serde_json::Value, Box<dyn std::error::Error>> {
+        let params: Vec<(&str, icon_sdk::contract::ScoreValue)> = vec![
+            ("_owners", icon_sdk::contract::ScoreValue::List(owners.into_iter().map(icon_sdk::contract::ScoreValue::Address).collect())),
+        ];
+        self.contract.send(wallet, "mintBatch", &params, Some(icx_value), version, nid, nonce, step_limit).await
+    }
+}
+
+/// `Transfer(Address,Address,int,bytes)` event of `Irc2Token`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferEvent {
+    pub from: String,
+    pub to: String,
+    pub value: icon_sdk::num_bigint::BigInt,
+    pub data: Option<Vec<u8>>,
+}
+
+impl TransferEvent {
+    pub const SIGNATURE: &'static str = "Transfer(Address,Address,int,bytes)";
+
+    pub fn from_event_log(log: &icon_sdk::responses::EventLog) -> Option<Self> {
+        if log.indexed.first()?.as_deref() != Some(Self::SIGNATURE) {
+            return None;
+        }
+        let mut indexed = log.indexed.iter().skip(1);
+        let mut data = log.data.iter();
+
+        Some(Self {
+            from: icon_sdk::contract::decode_event_arg(indexed.next()?, "Address")?,
+            to: icon_sdk::contract::decode_event_arg(indexed.next()?, "Address")?,
+            value: icon_sdk::contract::decode_event_arg(indexed.next()?, "int")?,
+            data: icon_sdk::contract::decode_event_arg(data.next().unwrap_or(&None), "bytes")?,
+        })
+    }
+}
@@ -0,0 +1,67 @@
+// Generated by icon_sdk::codegen. Do not edit.
+
+pub struct KeywordScore {
+    contract: icon_sdk::contract::Contract,
+}
+
+impl KeywordScore {
+    const SCORE_API: &'static str = r###"[{"type":"function","name":"self","inputs":[{"name":"crate","type":"Address"},{"name":"super","type":"int"},{"name":"_yield","type":"bool"}],"outputs":[{"name":"","type":"int"}],"readonly":"0x1"},{"type":"function","name":"super","inputs":[{"name":"self","type":"str"},{"name":"type","type":"bytes","default":null}],"outputs":[]},{"type":"eventlog","name":"Crate","inputs":[{"name":"self","type":"Address","indexed":"0x1"},{"name":"super","type":"int"}],"outputs":[]}]"###;
+
+    pub fn new(icon_service: icon_sdk::icon_service::IconService, contract_address: &str) -> Self {
+        let score_api = icon_sdk::responses::ScoreApi::from_json(Self::SCORE_API).expect("Invalid SCORE API");
+
+        Self {
+            contract: icon_sdk::contract::Contract::with_api(icon_service, contract_address, score_api),
+        }
+    }
+
+    pub fn contract(&self) -> &icon_sdk::contract::Contract {
+        &self.contract
+    }
+
+    pub async fn self_(&self, crate_: &str, super_: icon_sdk::num_bigint::BigInt, r#yield: bool) -> Result<icon_sdk::num_bigint::BigInt, Box<dyn std::error::Error>> {
+        let params: Vec<(&str, icon_sdk::contract::ScoreValue)> = vec![
+            ("crate", icon_sdk::contract::ScoreValue::address(crate_)),
+            ("super", icon_sdk::contract::ScoreValue::from(super_)),
+            ("_yield", icon_sdk::contract::ScoreValue::from(r#yield)),
+        ];
+        let result = self.contract.call("self", &params).await?;
+
+        Ok(icon_sdk::contract::FromScoreValue::from_score_value(result)?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn super_(&self, wallet: &icon_sdk::wallet::Wallet, self_: &str, r#type: Option<&[u8]>, version: &str, nid: Option<&str>, nonce: &str, step_limit: &str) -> Result<icon_sdk::serde_json::Value, Box<dyn std::error::Error>> {
+        let mut params: Vec<(&str, icon_sdk::contract::ScoreValue)> = vec![
+            ("self", icon_sdk::contract::ScoreValue::from(self_)),
+        ];
+        if let Some(r#type) = r#type {
+            params.push(("type", icon_sdk::contract::ScoreValue::from(r#type)));
+        }
+        self.contract.send(wallet, "super", &params, None, version, nid, nonce, step_limit).await
+    }
+}
+
+/// `Crate(Address,int)` event of `KeywordScore`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateEvent {
+    pub self_: String,
+    pub super_: Option<icon_sdk::num_bigint::BigInt>,
+}
+
+impl CrateEvent {
+    pub const SIGNATURE: &'static str = "Crate(Address,int)";
+
+    pub fn from_event_log(log: &icon_sdk::responses::EventLog) -> Option<Self> {
+        if log.indexed.first()?.as_deref() != Some(Self::SIGNATURE) {
+            return None;
+        }
+        let mut indexed = log.indexed.iter().skip(1);
+        let mut data = log.data.iter();
+
+        Some(Self {
+            self_: icon_sdk::contract::decode_event_arg(indexed.next()?, "Address")?,
+            super_: icon_sdk::contract::decode_event_arg(data.next().unwrap_or(&None), "int")?,
+        })
+    }
+}
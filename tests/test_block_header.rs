@@ -0,0 +1,220 @@
+mod common;
+
+use base64::{Engine as _, engine::general_purpose as base64_encoder};
+use serde_json::json;
+use sha3::{Digest, Sha3_256};
+use icon_sdk::block_header::{decode_validators, BlockHeader, CommitVote, CommitVotes, PartSetId};
+use icon_sdk::icon_service::IconService;
+use icon_sdk::rlp::{Encodable, RlpItem};
+use icon_sdk::wallet::Wallet;
+
+const PROPOSER: &str = "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160";
+
+fn bytes(value: &[u8]) -> RlpItem {
+    RlpItem::Bytes(value.to_vec())
+}
+
+fn header_bytes(height: i64) -> Vec<u8> {
+    let mut proposer = vec![0x00];
+    proposer.extend(hex::decode(&PROPOSER[2..]).unwrap());
+    let result = RlpItem::List(vec![bytes(&[0x11; 32]), RlpItem::Nil, bytes(&[0x22; 32]), RlpItem::Nil]).encode();
+
+    RlpItem::List(vec![
+        2i64.to_rlp(),
+        height.to_rlp(),
+        1_700_000_000_000_000i64.to_rlp(),
+        bytes(&proposer),
+        bytes(&[0x01; 32]),
+        bytes(&[0x02; 32]),
+        bytes(&[0x03; 32]),
+        RlpItem::Nil,
+        bytes(&[0x04; 32]),
+        bytes(&[]),
+        bytes(&result),
+    ]).encode()
+}
+
+// Votes of the validators flagged in `signers`
+fn sign_votes(header: &BlockHeader, validators: &[Wallet], signers: &[bool]) -> CommitVotes {
+    let mut votes = CommitVotes {
+        round: 0,
+        block_part_set_id: Some(PartSetId { count: 1, hash: vec![0x05; 32] }),
+        items: Vec::new(),
+    };
+
+    votes.items = validators.iter().zip(signers).enumerate()
+        .map(|(i, (validator, signer))| {
+            if !signer {
+                return None;
+            }
+            let mut vote = CommitVote { timestamp: 1_700_000_000_000_100 + i as i64, signature: Vec::new() };
+            let signature = validator.sign_message(&votes.vote_message(header, &vote)).unwrap();
+            vote.signature = base64_encoder::STANDARD.decode(signature).unwrap();
+            Some(vote)
+        })
+        .collect();
+
+    votes
+}
+
+fn encode_votes(votes: &CommitVotes) -> Vec<u8> {
+    votes.rlp_bytes()
+}
+
+#[tokio::test]
+async fn test_decode_header() -> Result<(), ()> {
+    let encoded = header_bytes(0x1234);
+    let header = BlockHeader::from_bytes(&encoded).unwrap();
+
+    assert_eq!(header.version, 2);
+    assert_eq!(header.height, 0x1234);
+    assert_eq!(header.timestamp, 1_700_000_000_000_000);
+    assert_eq!(header.proposer.as_deref(), Some(PROPOSER));
+    assert_eq!(header.prev_hash, vec![0x01; 32]);
+    assert_eq!(header.next_validators_hash, vec![0x03; 32]);
+    assert!(header.patch_transactions_hash.is_empty());
+    assert_eq!(header.normal_transactions_hash, vec![0x04; 32]);
+    assert_eq!(header.hash(), Sha3_256::digest(&encoded).to_vec());
+
+    let result = header.block_result().unwrap();
+    assert_eq!(result.state_hash, vec![0x11; 32]);
+    assert!(result.patch_receipt_hash.is_empty());
+    assert_eq!(result.normal_receipt_hash, vec![0x22; 32]);
+
+    assert!(BlockHeader::from_bytes(&RlpItem::List(vec![2i64.to_rlp()]).encode()).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_verify_votes() -> Result<(), ()> {
+    let header = BlockHeader::from_bytes(&header_bytes(100)).unwrap();
+    let validators: Vec<Wallet> = (0..4).map(|_| Wallet::new(None)).collect();
+    let addresses: Vec<String> = validators.iter().map(Wallet::get_public_address).collect();
+
+    // Three of four is more than two thirds
+    let votes = sign_votes(&header, &validators, &[true, false, true, true]);
+    let decoded = CommitVotes::from_bytes(&encode_votes(&votes)).unwrap();
+    assert_eq!(decoded, votes);
+    assert!(decoded.verify(&header, &addresses).is_ok());
+
+    // Two of four is not
+    let votes = sign_votes(&header, &validators, &[true, false, false, true]);
+    assert!(votes.verify(&header, &addresses).is_err());
+
+    // Signatures must come from the validator at the same position
+    let votes = sign_votes(&header, &validators, &[true, true, true, true]);
+    let mut shuffled = addresses.clone();
+    shuffled.swap(0, 1);
+    shuffled.swap(2, 3);
+    assert!(votes.verify(&header, &shuffled).is_err());
+
+    // Votes for another block don't count
+    let other = BlockHeader::from_bytes(&header_bytes(101)).unwrap();
+    assert!(votes.verify(&other, &addresses).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_header_and_votes() -> Result<(), ()> {
+    let header = BlockHeader::from_bytes(&header_bytes(100)).unwrap();
+    let validators: Vec<Wallet> = (0..4).map(|_| Wallet::new(None)).collect();
+    let addresses: Vec<String> = validators.iter().map(Wallet::get_public_address).collect();
+    let votes = encode_votes(&sign_votes(&header, &validators, &[true, true, true, false]));
+
+    let url = common::serve_rpc(move |method, params| {
+        assert_eq!(params["height"], json!("0x64"));
+        match method {
+            "icx_getBlockHeaderByHeight" => Ok(json!(base64_encoder::STANDARD.encode(header_bytes(100)))),
+            "icx_getVotesByHeight" => Ok(json!(base64_encoder::STANDARD.encode(&votes))),
+            _ => Err((-32601, "MethodNotFound".to_string())),
+        }
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let header = icon_service.get_block_header_by_height(100).await.unwrap();
+    let votes = icon_service.get_votes_by_height(100).await.unwrap();
+    assert_eq!(header.height, 100);
+    assert!(votes.verify(&header, &addresses).is_ok());
+
+    Ok(())
+}
+
+fn validator_list(addresses: &[String]) -> Vec<u8> {
+    RlpItem::List(addresses.iter().map(|address| {
+        let mut address_bytes = vec![0x00];
+        address_bytes.extend(hex::decode(&address[2..]).unwrap());
+        RlpItem::Bytes(address_bytes)
+    }).collect()).encode()
+}
+
+#[tokio::test]
+async fn test_decode_validators() -> Result<(), ()> {
+    let addresses = vec![PROPOSER.to_string(), "hxb14e0c751899676a1a4e655a34063b42260f844b".to_string()];
+    assert_eq!(decode_validators(&validator_list(&addresses)).unwrap(), addresses);
+
+    assert!(decode_validators(&RlpItem::List(vec![bytes(&[0x00; 3])]).encode()).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_data_by_hash() -> Result<(), ()> {
+    let header = BlockHeader::from_bytes(&header_bytes(100)).unwrap();
+    let validators: Vec<Wallet> = (0..4).map(|_| Wallet::new(None)).collect();
+    let addresses: Vec<String> = validators.iter().map(Wallet::get_public_address).collect();
+
+    let validator_list = validator_list(&addresses);
+    let votes = encode_votes(&sign_votes(&header, &validators, &[true, true, false, true]));
+    let validators_hash = format!("0x{}", hex::encode(Sha3_256::digest(&validator_list)));
+    let votes_hash = format!("0x{}", hex::encode(Sha3_256::digest(&votes)));
+    let wrong_hash = format!("0x{}", "00".repeat(32));
+
+    let (served_validators, served_votes) = (validators_hash.clone(), votes_hash.clone());
+    let url = common::serve_rpc(move |method, params| {
+        assert_eq!(method, "icx_getDataByHash");
+        let hash = params["hash"].as_str().unwrap();
+        if hash == served_validators || hash == wrong_hash {
+            Ok(json!(base64_encoder::STANDARD.encode(&validator_list)))
+        } else if hash == served_votes {
+            Ok(json!(base64_encoder::STANDARD.encode(&votes)))
+        } else {
+            Err((-31004, "NotFound".to_string()))
+        }
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let validators = icon_service.get_validators_by_hash(&validators_hash).await.unwrap();
+    assert_eq!(validators, addresses);
+    let votes = icon_service.get_votes_by_hash(&votes_hash).await.unwrap();
+    assert!(votes.verify(&header, &validators).is_ok());
+
+    // Data not matching the requested hash is rejected
+    assert!(icon_service.get_data_by_hash(&format!("0x{}", "00".repeat(32))).await.is_err());
+
+    Ok(())
+}
+
+// Checks the header layout and the vote message against a mainnet block, with the hash and
+// signatures computed by goloop
+#[tokio::test]
+async fn test_verify_mainnet_block() -> Result<(), ()> {
+    let icon_service = IconService::new(None);
+    let height = 70_000_000u64;
+
+    let header = icon_service.get_block_header_by_height(height).await.unwrap();
+    let block = icon_service.get_block_by_height(&format!("0x{:x}", height)).await.unwrap();
+    let block_hash = block["result"]["block_hash"].as_str().unwrap();
+    assert_eq!(header.height, height);
+    assert_eq!(hex::encode(header.hash()), block_hash.trim_start_matches("0x"));
+
+    let previous = icon_service.get_block_header_by_height(height - 1).await.unwrap();
+    assert_eq!(header.prev_hash, previous.hash());
+
+    let validators = icon_service.get_validators_by_hash(&format!("0x{}", hex::encode(&previous.next_validators_hash))).await.unwrap();
+    let votes = icon_service.get_votes_by_height(height).await.unwrap();
+    assert!(votes.verify(&header, &validators).is_ok());
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use futures_util::StreamExt;
+use serde_json::{json, Value};
+use icon_sdk::block_stream::BlockCursor;
+use icon_sdk::error::IconError;
+use icon_sdk::icon_service::IconService;
+
+fn block(height: u64) -> Value {
+    json!({
+        "version": "2.0",
+        "height": height,
+        "block_hash": format!("{:064x}", height),
+        "prev_block_hash": format!("{:064x}", height.saturating_sub(1)),
+        "merkle_tree_root_hash": "",
+        "time_stamp": 1_700_000_000_000_000u64 + height,
+        "confirmed_transaction_list": [],
+    })
+}
+
+// A chain whose last block is `last_height`, which the test moves forward
+async fn serve_chain(last_height: Arc<AtomicU64>) -> IconService {
+    let url = common::serve_rpc(move |method, params| {
+        let last = last_height.load(Ordering::SeqCst);
+        match method {
+            "icx_getLastBlock" => Ok(block(last)),
+            "icx_getBlockByHeight" => {
+                let height = u64::from_str_radix(params["height"].as_str().unwrap().trim_start_matches("0x"), 16).unwrap();
+                if height > last {
+                    return Err((-31004, "NotFound".to_string()));
+                }
+                Ok(block(height))
+            },
+            _ => Err((-32601, "MethodNotFound".to_string())),
+        }
+    }).await;
+
+    IconService::new(Some(url))
+}
+
+#[tokio::test]
+async fn test_poll_blocks() -> Result<(), ()> {
+    let last_height = Arc::new(AtomicU64::new(6));
+    let icon_service = serve_chain(last_height.clone()).await;
+
+    let mut blocks = icon_service.poll_blocks(BlockCursor::new(3), Duration::from_millis(10));
+
+    // Catches up on the blocks already produced
+    for height in 3..=6 {
+        assert_eq!(blocks.next().await.unwrap().unwrap().height, height);
+    }
+
+    // Then waits for new ones
+    let next = tokio::spawn(async move { blocks.next().await.unwrap().unwrap() });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    last_height.store(8, Ordering::SeqCst);
+    assert_eq!(next.await.unwrap().height, 7);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_poll_blocks_resume() -> Result<(), ()> {
+    let icon_service = serve_chain(Arc::new(AtomicU64::new(10))).await;
+
+    let mut blocks = icon_service.poll_blocks(BlockCursor::new(4), Duration::from_millis(10));
+    let processed = blocks.next().await.unwrap().unwrap();
+    drop(blocks);
+
+    let path = std::env::temp_dir().join(format!("icon_sdk_cursor_{}.json", std::process::id()));
+    BlockCursor::after(&processed).save(&path).unwrap();
+    let cursor = BlockCursor::load(&path).unwrap().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(cursor, BlockCursor { next_height: 5, last_block_hash: Some(format!("{:064x}", 4)) });
+
+    let mut blocks = icon_service.poll_blocks(cursor, Duration::from_millis(10));
+    assert_eq!(blocks.next().await.unwrap().unwrap().height, 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_poll_blocks_chain_mismatch() -> Result<(), ()> {
+    let icon_service = serve_chain(Arc::new(AtomicU64::new(10))).await;
+
+    let cursor = BlockCursor { next_height: 5, last_block_hash: Some(format!("0x{:064x}", 99)) };
+    let results: Vec<_> = icon_service.poll_blocks(cursor, Duration::from_millis(10)).collect().await;
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results[0], Err(IconError::ChainMismatch { height: 5 })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_missing_cursor() -> Result<(), ()> {
+    let path = std::env::temp_dir().join("icon_sdk_missing_cursor.json");
+    assert_eq!(BlockCursor::load(path).unwrap(), None);
+
+    Ok(())
+}
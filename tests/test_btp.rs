@@ -0,0 +1,162 @@
+mod common;
+
+use base64::{Engine as _, engine::general_purpose as base64_encoder};
+use futures_util::StreamExt;
+use serde_json::json;
+use tokio::net::TcpListener;
+use icon_sdk::btp::BtpHeader;
+use icon_sdk::icon_service::IconService;
+use icon_sdk::requests::BlockId;
+use icon_sdk::rlp::{Encodable, RlpItem};
+
+fn bytes(value: &[u8]) -> RlpItem {
+    RlpItem::Bytes(value.to_vec())
+}
+
+fn btp_header(main_height: i64, network_id: i64) -> String {
+    let header = RlpItem::List(vec![
+        main_height.to_rlp(),
+        0i64.to_rlp(),
+        bytes(&[0x01; 32]),
+        RlpItem::List(vec![RlpItem::List(vec![1i64.to_rlp(), bytes(&[0x02; 32])])]),
+        network_id.to_rlp(),
+        6i64.to_rlp(),
+        bytes(&[0x03; 32]),
+        2i64.to_rlp(),
+        bytes(&[0x04; 32]),
+        RlpItem::Nil,
+    ]);
+
+    base64_encoder::STANDARD.encode(header.encode())
+}
+
+#[tokio::test]
+async fn test_decode_btp_header() -> Result<(), ()> {
+    let header = BtpHeader::from_base64(&btp_header(0x1234, 2)).unwrap();
+
+    assert_eq!(header.main_height, 0x1234);
+    assert_eq!(header.network_id, 2);
+    assert_eq!(header.update_number, 6);
+    assert_eq!(header.message_count, 2);
+    assert_eq!(header.network_section_to_root.len(), 1);
+    assert_eq!(header.network_section_to_root[0].value, vec![0x02; 32]);
+    assert_eq!(header.network_section_to_root[0].side, 1);
+    assert!(header.next_proof_context.is_empty());
+
+    assert!(BtpHeader::from_base64("not base64!").is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_btp_methods() -> Result<(), ()> {
+    let url = common::serve_rpc(|method, params| match method {
+        "btp_getNetworkInfo" => {
+            assert_eq!(params["id"], json!("0x2"));
+            assert_eq!(params["height"], json!("0x64"));
+            Ok(json!({
+                "startHeight": "0x10",
+                "networkTypeID": "0x1",
+                "networkTypeName": "eth",
+                "networkName": "0x1.bsc",
+                "open": "0x1",
+                "owner": "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160",
+                "nextMessageSN": "0x5",
+                "nextProofContextChanged": "0x0",
+                "prevNSHash": "0x01",
+                "lastNSHash": "0x02",
+                "networkID": "0x2",
+            }))
+        },
+        "btp_getNetworkTypeInfo" => {
+            assert!(params.get("height").is_none());
+            Ok(json!({
+                "networkTypeID": "0x1",
+                "networkTypeName": "eth",
+                "nextProofContext": "0x00",
+                "openNetworkIDs": ["0x2"],
+            }))
+        },
+        "btp_getMessages" => {
+            assert_eq!(params["networkID"], json!("0x2"));
+            Ok(json!([base64_encoder::STANDARD.encode(b"message")]))
+        },
+        "btp_getHeader" => Ok(json!(btp_header(100, 2))),
+        "btp_getProof" => Ok(json!(base64_encoder::STANDARD.encode(b"proof"))),
+        "btp_getSourceInformation" => Ok(json!({"srcNetworkUID": "0x1.icon", "networkTypeIDs": ["0x1"]})),
+        _ => Err((-32601, "MethodNotFound".to_string())),
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let network = icon_service.get_btp_network_info("0x2", BlockId::Height(100)).await.unwrap();
+    assert_eq!(network.network_name, "0x1.bsc");
+    assert_eq!(network.next_message_sn, "0x5");
+
+    let network_type = icon_service.get_btp_network_type_info("0x1", BlockId::Latest).await.unwrap();
+    assert_eq!(network_type.open_network_ids, vec!["0x2"]);
+
+    let messages = icon_service.get_btp_messages(100, "0x2").await.unwrap();
+    assert_eq!(messages, vec![b"message".to_vec()]);
+
+    let header = icon_service.get_btp_header(100, "0x2").await.unwrap();
+    assert_eq!(header.main_height, 100);
+
+    assert_eq!(icon_service.get_btp_proof(100, "0x2").await.unwrap(), b"proof".to_vec());
+
+    let source = icon_service.get_btp_source_information().await.unwrap();
+    assert_eq!(source.src_network_uid, "0x1.icon");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_btp() -> Result<(), ()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let icon_service = IconService::new(Some(format!("http://{}/api/v3", listener.local_addr().unwrap())));
+    let proof = base64_encoder::STANDARD.encode(b"proof");
+
+    tokio::spawn(common::serve_websocket(listener, vec![
+        (json!({"height": "0x5", "networkID": "0x2", "proofFlag": "0x1"}), json!({"code": 0}), vec![
+            json!({"header": btp_header(5, 2), "proof": proof}),
+            json!({"header": btp_header(9, 2), "proof": proof}),
+        ]),
+        // Reconnects after the last BTP block seen
+        (json!({"height": "0xa", "networkID": "0x2"}), json!({"code": 0}), vec![
+            json!({"header": btp_header(12, 2), "proof": proof}),
+        ]),
+    ]));
+
+    let notifications: Vec<_> = icon_service.subscribe_btp(2, true, 5).take(3).collect().await;
+    let heights: Vec<u64> = notifications.iter().map(|notification| notification.as_ref().unwrap().header.main_height).collect();
+    assert_eq!(heights, vec![5, 9, 12]);
+    assert_eq!(notifications[0].as_ref().unwrap().proof.as_deref(), Some(&b"proof"[..]));
+
+    Ok(())
+}
+
+// Decodes a mainnet BTP header, checking the header layout and the order of the
+// network section to root nodes against goloop
+#[tokio::test]
+async fn test_decode_mainnet_btp_header() -> Result<(), ()> {
+    let icon_service = IconService::new(None);
+
+    let source = icon_service.get_btp_source_information().await.unwrap();
+    let network_type = icon_service.get_btp_network_type_info(&source.network_type_ids[0], BlockId::Latest).await.unwrap();
+    let network_id = &network_type.open_network_ids[0];
+    let network = icon_service.get_btp_network_info(network_id, BlockId::Latest).await.unwrap();
+
+    // The first BTP block of the network is at or right after the height it was opened
+    let start_height = u64::from_str_radix(network.start_height.trim_start_matches("0x"), 16).unwrap();
+    let header = match icon_service.get_btp_header(start_height, network_id).await {
+        Ok(header) => header,
+        Err(_) => icon_service.get_btp_header(start_height + 1, network_id).await.unwrap(),
+    };
+    assert!(header.main_height == start_height || header.main_height == start_height + 1);
+    assert_eq!(format!("0x{:x}", header.network_id), *network_id);
+    for node in &header.network_section_to_root {
+        assert!(node.side == 0 || node.side == 1);
+        assert_eq!(node.value.len(), 32);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use num_bigint::BigInt;
+use icon_sdk::codegen::generate_bindings;
+use icon_sdk::icon_service;
+use icon_sdk::responses::{EventLog, ScoreApi};
+
+#[allow(dead_code)]
+mod bindings {
+    include!("fixtures/irc2_bindings.rs");
+}
+
+#[allow(dead_code)]
+mod keyword_bindings {
+    include!("fixtures/keyword_bindings.rs");
+}
+
+#[tokio::test]
+async fn test_generate_bindings() -> Result<(), ()> {
+    let score_api = ScoreApi::from_json(include_str!("fixtures/irc2_score_api.json")).unwrap();
+    let bindings = generate_bindings("Irc2Token", &score_api);
+
+    // The fixture is compiled by the `bindings` module above, so the generated code stays valid
+    assert_eq!(bindings, include_str!("fixtures/irc2_bindings.rs"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_bindings_with_keywords() -> Result<(), ()> {
+    let score_api = ScoreApi::from_json(include_str!("fixtures/keyword_score_api.json")).unwrap();
+    let bindings = generate_bindings("KeywordScore", &score_api);
+
+    // `crate`, `self` and `super` can't be raw identifiers, the fixture compiling proves the renames work
+    assert_eq!(bindings, include_str!("fixtures/keyword_bindings.rs"));
+    assert!(bindings.contains("pub async fn self_(&self, crate_: &str, super_:"));
+    // Reserved words like `yield` are raw identifiers
+    assert!(bindings.contains("r#yield: bool"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generated_bindings() -> Result<(), ()> {
+    let icon_service = icon_service::IconService::new(None);
+    let token = bindings::Irc2Token::new(icon_service, "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa");
+    assert_eq!(token.contract().address(), "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa");
+    assert!(token.contract().score_api().function("balanceOf").unwrap().is_readonly());
+
+    let log = EventLog {
+        score_address: "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa".to_string(),
+        indexed: vec![
+            Some("Transfer(Address,Address,int,bytes)".to_string()),
+            Some("hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160".to_string()),
+            Some("hxb14e0c751899676a1a4e655a34063b42260f844b".to_string()),
+            Some("0x64".to_string()),
+        ],
+        data: vec![Some("0x1234".to_string())],
+    };
+    let event = bindings::TransferEvent::from_event_log(&log).unwrap();
+    assert_eq!(event.from, "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160");
+    assert_eq!(event.to, "hxb14e0c751899676a1a4e655a34063b42260f844b");
+    assert_eq!(event.value, BigInt::from(100));
+    assert_eq!(event.data, Some(vec![0x12, 0x34]));
+
+    // Most transfers carry no data
+    let without_data = EventLog { data: vec![None], ..log.clone() };
+    let event = bindings::TransferEvent::from_event_log(&without_data).unwrap();
+    assert_eq!(event.value, BigInt::from(100));
+    assert_eq!(event.data, None);
+
+    let other = EventLog {
+        indexed: vec![Some("Approval(Address,Address,int)".to_string())],
+        ..log
+    };
+    assert!(bindings::TransferEvent::from_event_log(&other).is_none());
+
+    Ok(())
+}
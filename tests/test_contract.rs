@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use num_bigint::BigInt;
+use serde_json::json;
+use icon_sdk::contract::{Contract, ScoreValue};
+use icon_sdk::icon_service;
+use icon_sdk::responses::ScoreApi;
+
+fn test_contract() -> Contract {
+    let score_api: ScoreApi = serde_json::from_value(json!([
+        {
+            "type": "function",
+            "name": "balanceOf",
+            "inputs": [{"name": "_owner", "type": "Address"}],
+            "outputs": [{"type": "int"}],
+            "readonly": "0x1"
+        },
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                {"name": "_to", "type": "Address"},
+                {"name": "_value", "type": "int"},
+                {"name": "_data", "type": "bytes", "default": null}
+            ],
+            "outputs": []
+        },
+        {
+            "type": "function",
+            "name": "setConfig",
+            "inputs": [
+                {"name": "_enabled", "type": "bool"},
+                {"name": "_weights", "type": "[]int"},
+                {
+                    "name": "_owner",
+                    "type": "struct",
+                    "fields": [
+                        {"name": "name", "type": "str"},
+                        {"name": "wallet", "type": "Address"}
+                    ]
+                }
+            ],
+            "outputs": []
+        },
+        {
+            "type": "function",
+            "name": "getOwners",
+            "inputs": [],
+            "outputs": [{"type": "[]Address"}],
+            "readonly": "0x1"
+        }
+    ])).unwrap();
+
+    Contract::with_api(icon_service::IconService::new(None), "cx9ab3078e72c8d9017194d17b34b1a47b661945ca", score_api)
+}
+
+#[tokio::test]
+async fn test_encode_call() -> Result<(), ()> {
+    let contract = test_contract();
+
+    let data = contract.encode_call("transfer", &[
+        ("_to", ScoreValue::address("hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd")),
+        ("_value", ScoreValue::from(1_000_000_000_000_000_000u64)),
+    ]).unwrap();
+    assert_eq!(data, json!({
+        "method": "transfer",
+        "params": {
+            "_to": "hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd",
+            "_value": "0xde0b6b3a7640000"
+        }
+    }));
+
+    let mut owner = BTreeMap::new();
+    owner.insert("name".to_string(), ScoreValue::from("alice"));
+    owner.insert("wallet".to_string(), ScoreValue::address("hxb14e0c751899676a1a4e655a34063b42260f844b"));
+    let data = contract.encode_call("setConfig", &[
+        ("_enabled", ScoreValue::from(true)),
+        ("_weights", ScoreValue::from(vec![ScoreValue::from(-1), ScoreValue::from(16)])),
+        ("_owner", ScoreValue::from(owner)),
+    ]).unwrap();
+    assert_eq!(data["params"], json!({
+        "_enabled": "0x1",
+        "_weights": ["-0x1", "0x10"],
+        "_owner": {"name": "alice", "wallet": "hxb14e0c751899676a1a4e655a34063b42260f844b"}
+    }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_encode_call_validation() -> Result<(), ()> {
+    let contract = test_contract();
+
+    assert!(contract.encode_call("mint", &[]).is_err());
+    assert!(contract.encode_call("balanceOf", &[]).is_err());
+    assert!(contract.encode_call("balanceOf", &[("_owner", ScoreValue::from("not an address"))]).is_err());
+    assert!(contract.encode_call("balanceOf", &[("_owner", ScoreValue::from(1))]).is_err());
+    assert!(contract.encode_call("balanceOf", &[
+        ("_owner", ScoreValue::address("hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd")),
+        ("_spender", ScoreValue::address("hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd")),
+    ]).is_err());
+
+    // Null is only accepted for optional inputs
+    assert!(contract.encode_call("balanceOf", &[("_owner", ScoreValue::Null)]).is_err());
+    let data = contract.encode_call("transfer", &[
+        ("_to", ScoreValue::address("hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd")),
+        ("_value", ScoreValue::from(1)),
+        ("_data", ScoreValue::Null),
+    ]).unwrap();
+    assert_eq!(data["params"]["_data"], json!(null));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decode_output() -> Result<(), ()> {
+    let contract = test_contract();
+
+    let balance = contract.decode_output("balanceOf", &json!("0x3635c9adc5dea00000")).unwrap();
+    assert_eq!(balance.as_int(), Some(&BigInt::from(1_000_000_000_000_000_000_000u128)));
+
+    let owners = contract.decode_output("getOwners", &json!(["hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd"])).unwrap();
+    assert_eq!(owners, ScoreValue::List(vec![ScoreValue::address("hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd")]));
+
+    assert!(contract.decode_output("balanceOf", &json!("not hex")).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_contract_call() -> Result<(), ()> {
+    let icon_service = icon_service::IconService::new(Some("https://lisbon.net.solidwallet.io/api/v3".to_string()));
+    let contract = Contract::new(icon_service, "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa").await.unwrap();
+    let res = contract.call("symbol", &[]).await;
+    match res {
+        Ok(symbol) => {
+            println!("{:?}", symbol);
+            assert_eq!(symbol.as_str(), Some("MIT"));
+        },
+        Err(e) => panic!("Error: {:?}", e),
+    }
+
+    Ok(())
+}
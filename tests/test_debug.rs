@@ -0,0 +1,102 @@
+mod common;
+
+use serde_json::json;
+use num_bigint::BigInt;
+use icon_sdk::debug::DebugClient;
+use icon_sdk::icon_service::IconService;
+use icon_sdk::requests::BlockId;
+use icon_sdk::responses::TraceLevel;
+use icon_sdk::transaction_builder::TransactionBuilder;
+
+#[tokio::test]
+async fn test_debug_url() -> Result<(), ()> {
+    let icon_service = IconService::new(None);
+    assert_eq!(icon_service.debug_url(), "https://api.icon.community/api/v3d");
+
+    let icon_service = IconService::new(Some("http://localhost:9080/api/v3/icon_dex/".to_string()));
+    assert_eq!(icon_service.debug_url(), "http://localhost:9080/api/v3d/icon_dex");
+
+    let icon_service = icon_service.with_debug_url("http://localhost:9081/debug");
+    assert_eq!(icon_service.debug_url(), "http://localhost:9081/debug");
+    assert_eq!(icon_service.debug().url(), "http://localhost:9081/debug");
+
+    assert_eq!(DebugClient::new("http://localhost:9081/api/v3d").url(), "http://localhost:9081/api/v3d");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_trace() -> Result<(), ()> {
+    let url = common::serve_rpc(|method, params| match method {
+        "debug_getTrace" if params["txHash"] == json!("0xa1b2") => {
+            Ok(json!({
+                "logs": [
+                    {"level": 2, "msg": "FRAME[1] START parent=FRAME[0]", "ts": 0},
+                    {"level": 1, "msg": "FRAME[1] CALL start from=hx1 to=cx2 value=0 steplimit=100000 dataType=call", "ts": 52},
+                    {"level": 2, "msg": "FRAME[1] CALL done status=Reverted(0) msg=insufficient balance", "ts": 130}
+                ],
+                "status": "0x0"
+            }))
+        },
+        "debug_getTrace" => Err((-31004, "not found".to_string())),
+        _ => Err((-32601, "method not found".to_string())),
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let trace = icon_service.get_trace("0xa1b2").await.unwrap();
+    assert_eq!(trace.status.as_deref(), Some("0x0"));
+    assert_eq!(trace.logs.len(), 3);
+    assert_eq!(trace.logs[1].level, TraceLevel::Trace);
+    assert_eq!(trace.logs[2].level, TraceLevel::System);
+    assert_eq!(trace.logs[2].message, "FRAME[1] CALL done status=Reverted(0) msg=insufficient balance");
+    assert_eq!(trace.logs[2].timestamp, 130);
+
+    assert!(icon_service.get_trace("0xc3d4").await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_debug_client() -> Result<(), ()> {
+    let url = common::serve_rpc(|method, params| match method {
+        "debug_getAccount" => {
+            assert_eq!(params["address"], json!("cx9ab3078e72c8d9017194d17b34b1a47b661945ca"));
+            assert_eq!(params["height"], json!("0x64"));
+            assert_eq!(params["filter"], json!("0x3"));
+            Ok(json!({
+                "balance": "0xde0b6b3a7640000",
+                "isContract": "0x1",
+                "contract": {"owner": "hxb14e0c751899676a1a4e655a34063b42260f844b"},
+                "flags": "0x0"
+            }))
+        },
+        "debug_estimateStep" => {
+            assert_eq!(params["to"], json!("hxf8689d6c4c8f333651469fdea2ac59a18f6c2421"));
+            assert!(params.get("stepLimit").is_none());
+            Ok(json!("0x186a0"))
+        },
+        _ => Err((-32601, "method not found".to_string())),
+    }).await;
+    let icon_service = IconService::new(Some(url));
+    let debug = icon_service.debug();
+
+    let account = debug.get_account("cx9ab3078e72c8d9017194d17b34b1a47b661945ca", BlockId::Height(100), Some(3)).await.unwrap();
+    assert_eq!(account.balance.as_deref(), Some("0xde0b6b3a7640000"));
+    assert_eq!(account.is_contract.as_deref(), Some("0x1"));
+    assert_eq!(account.contract.unwrap()["owner"], "hxb14e0c751899676a1a4e655a34063b42260f844b");
+    assert_eq!(account.other["flags"], "0x0");
+
+    let transaction = TransactionBuilder::new(&icon_service)
+        .from("hxb14e0c751899676a1a4e655a34063b42260f844b")
+        .to("hxf8689d6c4c8f333651469fdea2ac59a18f6c2421")
+        .value("1")
+        .version("0x3")
+        .nid("0x1")
+        .timestamp()
+        .step_limit("0x1")
+        .build()
+        .unwrap();
+    assert_eq!(debug.estimate_step(&transaction).await.unwrap(), BigInt::from(100_000));
+
+    Ok(())
+}
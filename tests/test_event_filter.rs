@@ -0,0 +1,77 @@
+use icon_sdk::event_filter::EventFilter;
+use icon_sdk::logs_bloom::LogsBloom;
+use icon_sdk::responses::EventLog;
+
+const TOKEN: &str = "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa";
+const FROM: &str = "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160";
+const TO: &str = "hxb14e0c751899676a1a4e655a34063b42260f844b";
+const TRANSFER: &str = "Transfer(Address,Address,int,bytes)";
+
+fn transfer_log(score_address: &str, from: &str, value: &str) -> EventLog {
+    EventLog {
+        score_address: score_address.to_string(),
+        indexed: vec![Some(TRANSFER.to_string()), Some(from.to_string()), Some(TO.to_string()), Some(value.to_string())],
+        data: vec![Some("0x".to_string())],
+    }
+}
+
+#[tokio::test]
+async fn test_matches() -> Result<(), ()> {
+    let log = transfer_log(TOKEN, FROM, "0xa");
+
+    assert!(EventFilter::new(TRANSFER).matches(&log));
+    assert!(EventFilter::new(TRANSFER).address(TOKEN).matches(&log));
+    assert!(!EventFilter::new(TRANSFER).address("cx9ab3078e72c8d9017194d17b34b1a47b661945ca").matches(&log));
+    assert!(!EventFilter::new("Transfer(Address,Address,int)").matches(&log));
+
+    // Per position, `None` matching anything
+    assert!(EventFilter::new(TRANSFER).indexed(vec![Some(FROM)]).matches(&log));
+    assert!(EventFilter::new(TRANSFER).indexed(vec![None, Some(TO)]).matches(&log));
+    assert!(!EventFilter::new(TRANSFER).indexed(vec![None, Some(FROM)]).matches(&log));
+
+    // Compared in binary form
+    assert!(EventFilter::new(TRANSFER).indexed(vec![None, None, Some("0x0a")]).matches(&log));
+    assert!(!EventFilter::new(TRANSFER).indexed(vec![None, None, Some("0xb")]).matches(&log));
+
+    // Data follows the indexed arguments of the log
+    assert!(EventFilter::new(TRANSFER).data(vec![Some("0x")]).matches(&log));
+    assert!(!EventFilter::new(TRANSFER).data(vec![Some("0x01")]).matches(&log));
+    assert!(!EventFilter::new(TRANSFER).data(vec![None, Some("0x")]).matches(&log));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_matching_events() -> Result<(), ()> {
+    let logs = vec![
+        transfer_log(TOKEN, FROM, "0x1"),
+        transfer_log(TOKEN, TO, "0x2"),
+        transfer_log(TOKEN, FROM, "0x3"),
+    ];
+
+    let filter = EventFilter::new(TRANSFER).indexed(vec![Some(FROM)]);
+    assert_eq!(filter.matching_events(&logs), vec![0, 2]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_validate() -> Result<(), ()> {
+    assert!(EventFilter::new(TRANSFER).indexed(vec![Some(FROM), None, Some("0x1")]).data(vec![Some("0x")]).validate().is_ok());
+    assert!(EventFilter::new("Transfer").validate().is_err());
+    assert!(EventFilter::new(TRANSFER).indexed(vec![Some("not an address")]).validate().is_err());
+    assert!(EventFilter::new(TRANSFER).data(vec![None, None, None, None, None]).validate().is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bloom() -> Result<(), ()> {
+    let logs = vec![transfer_log(TOKEN, FROM, "0x1")];
+    let bloom = LogsBloom::from_event_logs(&logs).unwrap();
+
+    assert!(bloom.contains_bloom(&EventFilter::new(TRANSFER).address(TOKEN).indexed(vec![Some(FROM)]).bloom().unwrap()));
+    assert!(!bloom.contains_bloom(&EventFilter::new(TRANSFER).indexed(vec![Some(TO)]).bloom().unwrap()));
+
+    Ok(())
+}
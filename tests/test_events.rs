@@ -0,0 +1,85 @@
+use num_bigint::BigInt;
+use icon_sdk::events::{decode_event_log, EventArg, EventSignature, ICXTransfer, IRC2Transfer, StandardEvent};
+use icon_sdk::responses::EventLog;
+
+fn event_log(score_address: &str, indexed: &[&str], data: &[Option<&str>]) -> EventLog {
+    EventLog {
+        score_address: score_address.to_string(),
+        indexed: indexed.iter().map(|value| Some(value.to_string())).collect(),
+        data: data.iter().map(|value| value.map(|value| value.to_string())).collect(),
+    }
+}
+
+#[tokio::test]
+async fn test_parse_signature() -> Result<(), ()> {
+    let signature = EventSignature::parse("Transfer(Address,Address,int,bytes)").unwrap();
+    assert_eq!(signature.name, "Transfer");
+    assert_eq!(signature.types, vec!["Address", "Address", "int", "bytes"]);
+
+    assert!(EventSignature::parse("Paused()").unwrap().types.is_empty());
+    assert!(EventSignature::parse("Transfer").is_err());
+    assert!(EventSignature::parse("(int)").is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decode_event_log() -> Result<(), ()> {
+    let log = event_log(
+        "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa",
+        &["Voted(Address,bool)", "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160"],
+        &[Some("0x1")],
+    );
+    let event = decode_event_log(&log).unwrap();
+    assert_eq!(event.name, "Voted");
+    assert_eq!(event.indexed(), &[EventArg::Address("hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160".to_string())]);
+    assert_eq!(event.data(), &[EventArg::Bool(true)]);
+
+    let log = event_log("cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa", &["Message(str,int)", "hello"], &[None]);
+    let event = decode_event_log(&log).unwrap();
+    assert_eq!(event.args, vec![EventArg::Str("hello".to_string()), EventArg::Null]);
+
+    let log = event_log("cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa", &["Message(str,int)", "hello"], &[]);
+    assert!(decode_event_log(&log).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_standard_events() -> Result<(), ()> {
+    let log = event_log(
+        "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa",
+        &[
+            "Transfer(Address,Address,int,bytes)",
+            "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160",
+            "hxb14e0c751899676a1a4e655a34063b42260f844b",
+            "0xaadec983fcff3ffff",
+        ],
+        &[Some("0x")],
+    );
+    let transfer = IRC2Transfer::from_event_log(&log).unwrap();
+    assert_eq!(transfer.token, "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa");
+    assert_eq!(transfer.value, BigInt::from(196_999_999_999_999_999_999u128));
+    assert!(transfer.data.is_empty());
+    assert!(ICXTransfer::from_event_log(&log).is_none());
+
+    let log = event_log(
+        "cx9ab3078e72c8d9017194d17b34b1a47b661945ca",
+        &[
+            "ICXTransfer(Address,Address,int)",
+            "cx9ab3078e72c8d9017194d17b34b1a47b661945ca",
+            "hxb14e0c751899676a1a4e655a34063b42260f844b",
+            "0xde0b6b3a7640000",
+        ],
+        &[],
+    );
+    match StandardEvent::from_event_log(&log) {
+        Some(StandardEvent::ICXTransfer(transfer)) => {
+            assert_eq!(transfer.from, "cx9ab3078e72c8d9017194d17b34b1a47b661945ca");
+            assert_eq!(transfer.amount, BigInt::from(1_000_000_000_000_000_000u64));
+        },
+        _ => panic!("Expected an ICXTransfer"),
+    }
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use serde_json::json;
 use icon_sdk::icon_service;
+use icon_sdk::requests::GetBlockByHeight;
 use icon_sdk::wallet::Wallet;
 
 #[tokio::test]
@@ -131,7 +132,7 @@ async fn test_send_transaction() -> Result<(), ()> {
         "hxf8689d6c4c8f333651469fdea2ac59a18f6c2421",
         "1.31231232",
         "0x3",
-        "0x2",
+        Some("0x2"),
         "0x1",
         "0x186a0"
     ).await;
@@ -158,7 +159,7 @@ async fn test_send_transaction_with_message() -> Result<(), ()> {
         "hxf8689d6c4c8f333651469fdea2ac59a18f6c2421",
         "1.31231232",
         "0x3",
-        "0x2",
+        Some("0x2"),
         "0x1",
         "0x186a00",
         "Test message"
@@ -175,3 +176,33 @@ async fn test_send_transaction_with_message() -> Result<(), ()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_request() -> Result<(), ()> {
+    let icon_service = icon_service::IconService::new(None);
+    let res = icon_service.request(&GetBlockByHeight { height: "0x1".to_string() }).await;
+    match res {
+        Ok(block) => {
+            println!("{:?}", block);
+            assert_eq!(block.height, 1);
+        },
+        Err(e) => panic!("Error: {:?}", e),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_score_api() -> Result<(), ()> {
+    let icon_service = icon_service::IconService::new(None);
+    let res = icon_service.get_score_api("cx9ab3078e72c8d9017194d17b34b1a47b661945ca").await;
+    match res {
+        Ok(score_api) => {
+            println!("{:?}", score_api);
+            assert!(score_api.function("balanceOf").unwrap().is_readonly());
+        },
+        Err(e) => panic!("Error: {:?}", e),
+    }
+
+    Ok(())
+}
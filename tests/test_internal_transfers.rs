@@ -0,0 +1,147 @@
+mod common;
+
+use num_bigint::BigInt;
+use serde_json::{json, Value};
+use icon_sdk::events::ICXTransfer;
+use icon_sdk::icon_service::IconService;
+use icon_sdk::responses::{Trace, TransactionResult};
+
+const SENDER: &str = "hxb14e0c751899676a1a4e655a34063b42260f844b";
+const SCORE: &str = "cx9ab3078e72c8d9017194d17b34b1a47b661945ca";
+const OTHER_SCORE: &str = "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa";
+const RECIPIENT: &str = "hxf8689d6c4c8f333651469fdea2ac59a18f6c2421";
+
+fn icx_transfer_log(score_address: &str, from: &str, to: &str, amount: &str) -> Value {
+    json!({
+        "scoreAddress": score_address,
+        "indexed": ["ICXTransfer(Address,Address,int)", from, to, amount],
+        "data": []
+    })
+}
+
+fn transaction_result(status: &str) -> Value {
+    json!({
+        "status": status,
+        "to": SCORE,
+        "txHash": "0xa1b2",
+        "txIndex": "0x1",
+        "blockHeight": "0x64",
+        "blockHash": "0xc3d4",
+        "cumulativeStepUsed": "0x1d4c0",
+        "stepUsed": "0x1d4c0",
+        "stepPrice": "0x2e90edd00",
+        "eventLogs": [
+            icx_transfer_log(SCORE, SCORE, RECIPIENT, "0xde0b6b3a7640000"),
+            // Emitted by another SCORE on behalf of SCORE, not a transfer
+            icx_transfer_log(OTHER_SCORE, SCORE, RECIPIENT, "0x1"),
+            icx_transfer_log(OTHER_SCORE, OTHER_SCORE, SCORE, "0x2"),
+        ]
+    })
+}
+
+#[tokio::test]
+async fn test_transfers_from_transaction_result() -> Result<(), ()> {
+    let result: TransactionResult = serde_json::from_value(transaction_result("0x1")).unwrap();
+    let transfers = ICXTransfer::from_transaction_result(&result);
+
+    assert_eq!(transfers, vec![
+        ICXTransfer { from: SCORE.to_string(), to: RECIPIENT.to_string(), amount: BigInt::from(1_000_000_000_000_000_000u64) },
+        ICXTransfer { from: OTHER_SCORE.to_string(), to: SCORE.to_string(), amount: BigInt::from(2) },
+    ]);
+
+    let result: TransactionResult = serde_json::from_value(transaction_result("0x0")).unwrap();
+    assert!(ICXTransfer::from_transaction_result(&result).is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfers_from_trace() -> Result<(), ()> {
+    let trace: Trace = serde_json::from_value(json!({
+        "logs": [
+            {"level": 2, "msg": "FRAME[1] START parent=FRAME[0]", "ts": 0},
+            {"level": 2, "msg": format!("FRAME[1] CALL start from={} to={} value=5 steplimit=4096 dataType=call data={{\"method\":\"claim\"}}", SENDER, SCORE), "ts": 3},
+            {"level": 2, "msg": "FRAME[2] START parent=FRAME[1]", "ts": 10},
+            {"level": 2, "msg": format!("FRAME[2] TRANSFER start from={} to={} value=3", SCORE, RECIPIENT), "ts": 11},
+            {"level": 2, "msg": "FRAME[2] TRANSFER done status=Success steps=0", "ts": 12},
+            {"level": 2, "msg": "FRAME[3] START parent=FRAME[1]", "ts": 20},
+            {"level": 2, "msg": format!("FRAME[3] CALL start from={} to={} value=0x7 steplimit=0x800 dataType=call", SCORE, OTHER_SCORE), "ts": 21},
+            {"level": 2, "msg": "FRAME[4] START parent=FRAME[3]", "ts": 22},
+            {"level": 2, "msg": format!("FRAME[4] TRANSFER start from={} to={} value=1", OTHER_SCORE, RECIPIENT), "ts": 23},
+            {"level": 2, "msg": "FRAME[4] TRANSFER done status=Success steps=0", "ts": 24},
+            {"level": 2, "msg": "FRAME[3] CALL done status=UserReverted(1) msg=no steps=1200", "ts": 30},
+            {"level": 1, "msg": "FRAME[1] insufficient balance", "ts": 31},
+            {"level": 2, "msg": "FRAME[1] CALL done status=Success steps=3500 price=12500000000", "ts": 40},
+            // Older nodes log without the frame prefix
+            {"level": 2, "msg": format!("TRANSFER start from={} to={} value=0x2", OTHER_SCORE, RECIPIENT), "ts": 41},
+            {"level": 2, "msg": "TRANSFER done status=Success", "ts": 42}
+        ],
+        "status": "0x1"
+    })).unwrap();
+
+    // The sender's own transfer is not internal, the reverted call's transfers never happened
+    assert_eq!(ICXTransfer::from_trace(&trace), vec![
+        ICXTransfer { from: SCORE.to_string(), to: RECIPIENT.to_string(), amount: BigInt::from(3) },
+        ICXTransfer { from: OTHER_SCORE.to_string(), to: RECIPIENT.to_string(), amount: BigInt::from(2) },
+    ]);
+
+    let failed = Trace { status: Some("0x0".to_string()), ..trace };
+    assert!(ICXTransfer::from_trace(&failed).is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_internal_transfers() -> Result<(), ()> {
+    let url = common::serve_rpc(|method, params| {
+        assert_eq!(params["txHash"], json!("0xa1b2"));
+        match method {
+            "icx_getTransactionResult" => Ok(transaction_result("0x1")),
+            "debug_getTrace" => Ok(json!({
+                "logs": [
+                    {"level": 2, "msg": format!("FRAME[2] TRANSFER start from={} to={} value=0x3", SCORE, RECIPIENT), "ts": 10},
+                    {"level": 2, "msg": "FRAME[2] TRANSFER done status=Success steps=0", "ts": 11}
+                ],
+                "status": "0x1"
+            })),
+            _ => Err((-32601, "method not found".to_string())),
+        }
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let transfers = icon_service.get_internal_transfers("0xa1b2").await.unwrap();
+    assert_eq!(transfers.len(), 2);
+    assert_eq!(transfers[0].to, RECIPIENT);
+
+    let transfers = icon_service.debug().get_internal_transfers("0xa1b2").await.unwrap();
+    assert_eq!(transfers, vec![ICXTransfer { from: SCORE.to_string(), to: RECIPIENT.to_string(), amount: BigInt::from(3) }]);
+
+    Ok(())
+}
+
+// Finds a mainnet transaction with internal transfers and checks that parsing its trace, as
+// served by the node's debug API, finds the same transfers as its ICXTransfer event logs
+#[tokio::test]
+async fn test_mainnet_transfers_from_trace() -> Result<(), ()> {
+    let icon_service = IconService::new(None);
+
+    for height in 70_000_000u64..70_000_200 {
+        let block = icon_service.get_block_by_height(&format!("0x{:x}", height)).await.unwrap();
+        for transaction in block["result"]["confirmed_transaction_list"].as_array().unwrap() {
+            let tx_hash = transaction["txHash"].as_str().unwrap();
+            let response = icon_service.get_transaction_result(tx_hash).await.unwrap();
+            let result: TransactionResult = serde_json::from_value(response["result"].clone()).unwrap();
+            let transfers = ICXTransfer::from_transaction_result(&result);
+            if transfers.is_empty() {
+                continue;
+            }
+
+            let trace = icon_service.get_trace(tx_hash).await.unwrap();
+            assert_eq!(ICXTransfer::from_trace(&trace), transfers, "trace of {}", tx_hash);
+
+            return Ok(());
+        }
+    }
+
+    panic!("No internal transfer found");
+}
@@ -100,7 +100,7 @@ async fn test_transfer() -> Result<(), ()> {
         "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160",
         "12.317",
         "0x3",
-        "0x2",
+        Some("0x2"),
         "0x1",
         "0x186a00"
     ).await;
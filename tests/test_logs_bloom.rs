@@ -0,0 +1,111 @@
+use num_bigint::BigInt;
+use icon_sdk::events::EventArg;
+use icon_sdk::icon_service::IconService;
+use icon_sdk::logs_bloom::{address_to_bytes, event_arg_to_bytes, event_bloom, LogsBloom};
+use icon_sdk::responses::{EventLog, TransactionResult};
+
+fn transfer_log() -> EventLog {
+    EventLog {
+        score_address: "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa".to_string(),
+        indexed: vec![
+            Some("Transfer(Address,Address,int,bytes)".to_string()),
+            Some("hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160".to_string()),
+            Some("hxb14e0c751899676a1a4e655a34063b42260f844b".to_string()),
+            Some("0x64".to_string()),
+        ],
+        data: vec![None],
+    }
+}
+
+#[tokio::test]
+async fn test_bloom_bytes() -> Result<(), ()> {
+    let mut bloom = LogsBloom::new();
+    assert!(!bloom.contains(b"hello"));
+    bloom.add(b"hello");
+    assert!(bloom.contains(b"hello"));
+
+    let hex = bloom.to_hex();
+    assert_eq!(hex.len(), 2 + 512);
+    assert_eq!(LogsBloom::from_hex(&hex).unwrap(), bloom);
+
+    // Compact form with leading zeros stripped
+    let compact = hex.trim_start_matches("0x").trim_start_matches("00");
+    assert_eq!(LogsBloom::from_hex(compact).unwrap(), bloom);
+
+    assert!(LogsBloom::from_hex(&format!("0x{}", "00".repeat(257))).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_event_arg_bytes() -> Result<(), ()> {
+    let address = address_to_bytes("cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa").unwrap();
+    assert_eq!(address.len(), 21);
+    assert_eq!(address[0], 1);
+    assert_eq!(address_to_bytes("hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160").unwrap()[0], 0);
+    assert!(address_to_bytes("0x8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160").is_err());
+
+    assert_eq!(event_arg_to_bytes(&EventArg::Int(BigInt::from(0))).unwrap(), vec![0x00]);
+    assert_eq!(event_arg_to_bytes(&EventArg::Int(BigInt::from(128))).unwrap(), vec![0x00, 0x80]);
+    assert_eq!(event_arg_to_bytes(&EventArg::Int(BigInt::from(-1))).unwrap(), vec![0xff]);
+    assert_eq!(event_arg_to_bytes(&EventArg::Bool(true)).unwrap(), vec![0x01]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_event_bloom_matching() -> Result<(), ()> {
+    let receipt_bloom = LogsBloom::from_event_logs(&[transfer_log()]).unwrap();
+
+    let any_transfer = event_bloom(None, "Transfer(Address,Address,int,bytes)", &[]).unwrap();
+    assert!(receipt_bloom.contains_bloom(&any_transfer));
+
+    let token_transfer_to = event_bloom(
+        Some("cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa"),
+        "Transfer(Address,Address,int,bytes)",
+        &[None, Some("hxb14e0c751899676a1a4e655a34063b42260f844b")],
+    ).unwrap();
+    assert!(receipt_bloom.contains_bloom(&token_transfer_to));
+
+    let other_token = event_bloom(Some("cx9ab3078e72c8d9017194d17b34b1a47b661945ca"), "Transfer(Address,Address,int,bytes)", &[]).unwrap();
+    assert!(!receipt_bloom.contains_bloom(&other_token));
+
+    let other_event = event_bloom(None, "Approval(Address,Address,int)", &[]).unwrap();
+    assert!(!receipt_bloom.contains_bloom(&other_event));
+
+    assert!(event_bloom(None, "Transfer(Address,Address,int,bytes)", &[None, None, None, None, None]).is_err());
+
+    Ok(())
+}
+
+// Rebuilds the logs bloom of mainnet receipts from their event logs, checking the address
+// prefix, the position prefixes and the argument encodings against the blooms goloop computed,
+// then checks that the next header's bloom is the union of the block's receipt blooms
+#[tokio::test]
+async fn test_mainnet_logs_bloom() -> Result<(), ()> {
+    let icon_service = IconService::new(None);
+    let mut indexed_args = 0;
+
+    for height in 70_000_000u64..70_000_010 {
+        let block = icon_service.get_block_by_height(&format!("0x{:x}", height)).await.unwrap();
+        let mut block_bloom = LogsBloom::new();
+
+        for transaction in block["result"]["confirmed_transaction_list"].as_array().unwrap() {
+            let response = icon_service.get_transaction_result(transaction["txHash"].as_str().unwrap()).await.unwrap();
+            let result: TransactionResult = serde_json::from_value(response["result"].clone()).unwrap();
+            let expected = LogsBloom::from_hex(result.logs_bloom.as_deref().unwrap()).unwrap();
+
+            assert_eq!(LogsBloom::from_event_logs(&result.event_logs).unwrap(), expected, "receipt {}", result.tx_hash);
+            indexed_args += result.event_logs.iter().map(|log| log.indexed.len() - 1).sum::<usize>();
+            block_bloom.merge(&expected);
+        }
+
+        let next_header = icon_service.get_block_header_by_height(height + 1).await.unwrap();
+        assert_eq!(next_header.logs_bloom, block_bloom, "block {}", height);
+    }
+
+    // Not only signatures were checked
+    assert!(indexed_args > 0);
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use num_bigint::BigInt;
+use serde_json::json;
+use icon_sdk::icon_service::IconService;
+use icon_sdk::transaction_builder::TransactionBuilder;
+use icon_sdk::utils::transaction_types::TransactionType;
+use icon_sdk::wallet::Wallet;
+
+fn network_info() -> serde_json::Value {
+    json!({
+        "platform": "icon",
+        "nid": "0x7",
+        "channel": "icon_dex",
+        "earliest": "0x0",
+        "latest": "0x1234",
+        "stepPrice": "0x2e90edd00"
+    })
+}
+
+#[tokio::test]
+async fn test_get_total_supply() -> Result<(), ()> {
+    let url = common::serve_rpc(|method, _| match method {
+        "icx_getTotalSupply" => Ok(json!("0x33b2e3c9fd0803ce8000000")),
+        _ => Err((-32601, "method not found".to_string())),
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let total_supply = icon_service.get_total_supply().await.unwrap();
+    assert_eq!(total_supply, "1000000000000000000000000000".parse::<BigInt>().unwrap());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_network_info() -> Result<(), ()> {
+    let url = common::serve_rpc(|method, _| match method {
+        "icx_getNetworkInfo" => Ok(network_info()),
+        _ => Err((-32601, "method not found".to_string())),
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let info = icon_service.get_network_info().await.unwrap();
+    assert_eq!(info.platform, "icon");
+    assert_eq!(info.nid, "0x7");
+    assert_eq!(info.channel, "icon_dex");
+    assert_eq!(info.earliest, "0x0");
+    assert_eq!(info.latest.as_deref(), Some("0x1234"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_discover_nid() -> Result<(), ()> {
+    let url = common::serve_rpc(|method, params| match method {
+        "icx_getNetworkInfo" => Ok(network_info()),
+        "icx_sendTransaction" => {
+            assert_eq!(params["nid"], json!("0x7"));
+            Ok(json!("0x1"))
+        },
+        _ => Err((-32601, "method not found".to_string())),
+    }).await;
+    let icon_service = IconService::new(Some(url)).discover_nid().await.unwrap();
+    assert_eq!(icon_service.nid(), Some("0x7"));
+
+    let wallet = Wallet::new(None);
+    let transaction = TransactionBuilder::new(&icon_service)
+        .method(TransactionType::SendTransaction.as_str())
+        .from(wallet.get_public_address().as_str())
+        .to("hxf8689d6c4c8f333651469fdea2ac59a18f6c2421")
+        .value("1")
+        .version("0x3")
+        .timestamp()
+        .step_limit("0x186a0")
+        .sign(wallet.get_private_key().as_str())
+        .build()
+        .unwrap();
+    transaction.send().await.unwrap();
+
+    let response = icon_service.send_transaction(wallet, "hxf8689d6c4c8f333651469fdea2ac59a18f6c2421", "1", "0x3", None, "0x1", "0x186a0").await.unwrap();
+    assert_eq!(response["result"], json!("0x1"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_without_nid() -> Result<(), ()> {
+    let icon_service = IconService::new(Some("http://127.0.0.1:1/api/v3".to_string()));
+
+    let res = icon_service.send_transaction(Wallet::new(None), "hxf8689d6c4c8f333651469fdea2ac59a18f6c2421", "1", "0x3", None, "0x1", "0x186a0").await;
+    assert!(res.unwrap_err().to_string().contains("missing nid"));
+
+    Ok(())
+}
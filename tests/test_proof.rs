@@ -0,0 +1,227 @@
+mod common;
+
+use serde_json::json;
+use sha3::{Digest, Sha3_256};
+use icon_sdk::block_header::BlockHeader;
+use icon_sdk::icon_service::IconService;
+use icon_sdk::proof::{proof_key, verify_event_proof, verify_merkle_proof, verify_result_proof};
+use icon_sdk::responses::{EventLog, TransactionResult};
+use icon_sdk::rlp::{Encodable, RlpItem};
+
+const TOKEN: &str = "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa";
+const FROM: &str = "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160";
+const TO: &str = "hxb14e0c751899676a1a4e655a34063b42260f844b";
+const TRANSFER: &str = "Transfer(Address,Address,int,bytes)";
+
+fn bytes(value: &[u8]) -> RlpItem {
+    RlpItem::Bytes(value.to_vec())
+}
+
+fn address(address: &str) -> Vec<u8> {
+    let mut bytes = vec![if address.starts_with("cx") { 0x01 } else { 0x00 }];
+    bytes.extend(hex::decode(&address[2..]).unwrap());
+    bytes
+}
+
+// Nodes shorter than a hash are embedded, the others referenced by hash
+fn link(node: &RlpItem) -> RlpItem {
+    let encoded = node.encode();
+    if encoded.len() < 32 {
+        node.clone()
+    } else {
+        bytes(&Sha3_256::digest(&encoded))
+    }
+}
+
+// A trie of up to 16 values keyed by `proof_key(index)`: an extension for the leading zero nibble,
+// a branch on the second nibble, then one leaf per value. Returns the root and the proof of each value.
+fn build_trie(values: &[Vec<u8>]) -> (Vec<u8>, Vec<Vec<Vec<u8>>>) {
+    let leaves: Vec<RlpItem> = values.iter().map(|value| RlpItem::List(vec![bytes(&[0x20]), bytes(value)])).collect();
+
+    let mut children: Vec<RlpItem> = (0..17).map(|_| bytes(&[])).collect();
+    for (i, leaf) in leaves.iter().enumerate() {
+        children[i] = link(leaf);
+    }
+    let branch = RlpItem::List(children);
+    let extension = RlpItem::List(vec![bytes(&[0x10]), link(&branch)]);
+
+    let root = Sha3_256::digest(extension.encode()).to_vec();
+    let proofs = leaves.iter()
+        .map(|leaf| {
+            [&extension, &branch, leaf].iter()
+                .map(|node| node.encode())
+                .filter(|encoded| encoded.len() >= 32 || encoded == &extension.encode())
+                .collect()
+        })
+        .collect();
+
+    (root, proofs)
+}
+
+fn event_log(value: u8) -> RlpItem {
+    RlpItem::List(vec![
+        bytes(&address(TOKEN)),
+        RlpItem::List(vec![bytes(TRANSFER.as_bytes()), bytes(&address(FROM)), bytes(&address(TO)), bytes(&[value])]),
+        RlpItem::List(vec![bytes(&[])]),
+    ])
+}
+
+fn receipt(event_logs_hash: &[u8]) -> RlpItem {
+    RlpItem::List(vec![
+        1i64.to_rlp(),
+        bytes(&address(TOKEN)),
+        bytes(&[0x01, 0x00]),
+        bytes(&[0x01, 0x00]),
+        bytes(&[0x02, 0xe9, 0x0e, 0xdd, 0x00]),
+        bytes(&[]),
+        RlpItem::List(vec![]),
+        RlpItem::Nil,
+        bytes(event_logs_hash),
+    ])
+}
+
+fn header(receipts_root: &[u8]) -> BlockHeader {
+    let result = RlpItem::List(vec![bytes(&[0x11; 32]), RlpItem::Nil, bytes(receipts_root), RlpItem::Nil]).encode();
+    let header = RlpItem::List(vec![
+        2i64.to_rlp(),
+        101i64.to_rlp(),
+        1_700_000_000_000_000i64.to_rlp(),
+        RlpItem::Nil,
+        bytes(&[0x01; 32]),
+        bytes(&[0x02; 32]),
+        bytes(&[0x03; 32]),
+        RlpItem::Nil,
+        RlpItem::Nil,
+        bytes(&[]),
+        bytes(&result),
+    ]);
+
+    BlockHeader::from_bytes(&header.encode()).unwrap()
+}
+
+#[tokio::test]
+async fn test_verify_merkle_proof() -> Result<(), ()> {
+    // Short values leave embedded leaves
+    let values = vec![b"a".to_vec(), b"b".to_vec(), vec![0x42; 40]];
+    let (root, proofs) = build_trie(&values);
+
+    for (i, value) in values.iter().enumerate() {
+        assert_eq!(&verify_merkle_proof(&root, &proof_key(i as u64), &proofs[i]).unwrap(), value);
+    }
+
+    // Keys missing from the trie
+    assert!(verify_merkle_proof(&root, &proof_key(3), &proofs[0]).is_err());
+    assert!(verify_merkle_proof(&root, &proof_key(0x20), &proofs[0]).is_err());
+
+    // Tampered or incomplete proofs
+    let mut tampered = proofs[2].clone();
+    let last = tampered.last_mut().unwrap();
+    *last.last_mut().unwrap() ^= 0x01;
+    assert!(verify_merkle_proof(&root, &proof_key(2), &tampered).is_err());
+    assert!(verify_merkle_proof(&root, &proof_key(2), &proofs[2][..1]).is_err());
+    assert!(verify_merkle_proof(&[0u8; 32], &proof_key(0), &proofs[0]).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_verify_result_and_event_proofs() -> Result<(), ()> {
+    let events: Vec<Vec<u8>> = (1..=2).map(|value| event_log(value).encode()).collect();
+    let (events_root, event_proofs) = build_trie(&events);
+
+    let receipts: Vec<Vec<u8>> = vec![receipt(&[0x33; 32]).encode(), receipt(&events_root).encode()];
+    let (receipts_root, receipt_proofs) = build_trie(&receipts);
+    let header = header(&receipts_root);
+
+    let receipt = verify_result_proof(&header, 1, &receipt_proofs[1]).unwrap();
+    assert!(receipt.is_success());
+    assert_eq!(receipt.to.as_deref(), Some(TOKEN));
+    assert_eq!(receipt.step_price, 12_500_000_000u64.into());
+    assert_eq!(receipt.event_logs_hash, events_root);
+    assert!(verify_result_proof(&header, 0, &receipt_proofs[1]).is_err());
+
+    let event = verify_event_proof(&receipt, 1, &event_proofs[1]).unwrap();
+    assert_eq!(event.score_address, TOKEN);
+
+    let log = EventLog {
+        score_address: TOKEN.to_string(),
+        indexed: vec![Some(TRANSFER.to_string()), Some(FROM.to_string()), Some(TO.to_string()), Some("0x2".to_string())],
+        data: vec![Some("0x".to_string())],
+    };
+    assert!(event.matches(&log));
+
+    let mut other = log.clone();
+    other.indexed[3] = Some("0x1".to_string());
+    assert!(!event.matches(&other));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_proofs() -> Result<(), ()> {
+    let events: Vec<Vec<u8>> = vec![event_log(1).encode()];
+    let (events_root, event_proofs) = build_trie(&events);
+    let (receipts_root, receipt_proofs) = build_trie(&[receipt(&events_root).encode()]);
+
+    let to_hex = |proof: &Vec<Vec<u8>>| proof.iter().map(|node| format!("0x{}", hex::encode(node))).collect::<Vec<String>>();
+    let result_proof = to_hex(&receipt_proofs[0]);
+    let event_proof = to_hex(&event_proofs[0]);
+
+    let url = common::serve_rpc(move |method, params| {
+        assert_eq!(params["hash"], json!(format!("0x{}", "ab".repeat(32))));
+        assert_eq!(params["index"], json!("0x0"));
+        match method {
+            "icx_getProofForResult" => Ok(json!(result_proof)),
+            "icx_getProofForEvents" => {
+                assert_eq!(params["events"], json!(["0x0"]));
+                Ok(json!([result_proof, event_proof]))
+            },
+            _ => Err((-32601, "MethodNotFound".to_string())),
+        }
+    }).await;
+    let icon_service = IconService::new(Some(url));
+    let block_hash = format!("0x{}", "ab".repeat(32));
+    let header = header(&receipts_root);
+
+    let proof = icon_service.get_proof_for_result(&block_hash, "0x0").await.unwrap();
+    assert!(verify_result_proof(&header, 0, &proof).is_ok());
+
+    let proofs = icon_service.get_proof_for_events(&block_hash, "0x0", &["0x0"]).await.unwrap();
+    assert_eq!(proofs.len(), 2);
+    let receipt = verify_result_proof(&header, 0, &proofs[0]).unwrap();
+    assert!(verify_event_proof(&receipt, 0, &proofs[1]).is_ok());
+
+    Ok(())
+}
+
+// Checks the receipt and event log layouts and the trie proofs against a mainnet block, using
+// the first transaction of the block, which always emits event logs
+#[tokio::test]
+async fn test_verify_mainnet_proofs() -> Result<(), ()> {
+    let icon_service = IconService::new(None);
+    let height = 70_000_000u64;
+
+    let block = icon_service.get_block_by_height(&format!("0x{:x}", height)).await.unwrap();
+    let block_hash = format!("0x{}", block["result"]["block_hash"].as_str().unwrap().trim_start_matches("0x"));
+    let tx_hash = block["result"]["confirmed_transaction_list"][0]["txHash"].as_str().unwrap();
+    let response = icon_service.get_transaction_result(tx_hash).await.unwrap();
+    let result: TransactionResult = serde_json::from_value(response["result"].clone()).unwrap();
+    assert!(!result.event_logs.is_empty());
+
+    // The receipts of a block are committed by the next one
+    let header = icon_service.get_block_header_by_height(height + 1).await.unwrap();
+
+    let proof = icon_service.get_proof_for_result(&block_hash, "0x0").await.unwrap();
+    let receipt = verify_result_proof(&header, 0, &proof).unwrap();
+    assert_eq!(receipt.is_success(), result.is_success());
+    assert_eq!(format!("0x{:x}", receipt.step_used), result.step_used);
+    assert_eq!(format!("0x{:x}", receipt.step_price), result.step_price);
+    assert_eq!(receipt.event_logs.len(), result.event_logs.len());
+
+    let proofs = icon_service.get_proof_for_events(&block_hash, "0x0", &["0x0"]).await.unwrap();
+    let receipt = verify_result_proof(&header, 0, &proofs[0]).unwrap();
+    let event = verify_event_proof(&receipt, 0, &proofs[1]).unwrap();
+    assert!(event.matches(&result.event_logs[0]));
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use serde_json::json;
+use icon_sdk::requests::{BlockId, Call, GetBalance, GetBlockByHeight, GetLastBlock, GetProofForEvents, Request};
+use icon_sdk::responses::{Block, ScoreApi, ScoreApiType, TransactionResult};
+
+#[tokio::test]
+async fn test_request_params() -> Result<(), ()> {
+    assert_eq!(GetLastBlock.method().as_str(), "icx_getLastBlock");
+    assert!(GetLastBlock.params().is_empty());
+
+    let request = GetBlockByHeight { height: "0x3".to_string() };
+    assert_eq!(request.method().as_str(), "icx_getBlockByHeight");
+    assert_eq!(request.params()["height"], "0x3");
+
+    let request = Call {
+        from: None,
+        to: "cx9ab3078e72c8d9017194d17b34b1a47b661945ca".to_string(),
+        data: json!({"method": "name"}),
+        height: None,
+    };
+    assert_eq!(request.params()["dataType"], "call");
+    assert_eq!(request.params()["data"]["method"], "name");
+    assert!(!request.params().contains_key("from"));
+    assert!(!request.params().contains_key("height"));
+
+    let request = GetBalance { address: "hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd".to_string(), height: BlockId::Height(100).height() };
+    assert_eq!(request.params()["height"], "0x64");
+    assert_eq!(BlockId::Latest.height(), None);
+    assert_eq!(BlockId::from(255), BlockId::Height(255));
+
+    let request = GetProofForEvents {
+        hash: "0x1234".to_string(),
+        index: "0x0".to_string(),
+        events: vec!["0x0".to_string(), "0x2".to_string()],
+    };
+    assert_eq!(request.params()["events"], json!(["0x0", "0x2"]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decode_block() -> Result<(), ()> {
+    let block: Block = serde_json::from_value(json!({
+        "version": "2.0",
+        "height": 3,
+        "signature": "",
+        "prev_block_hash": "aa34d3b1b6b0e7ed6abbeaaad3bbf5fdf1e0ab7d4e1b0a1e0d4f1ae1a5e3c2f1",
+        "merkle_tree_root_hash": "e3e3d1a4c84f0f1a1fa7c8f5dbb2e5f1c8b0a5f2b9b3d7d3e1ebf2e2d1b0a3c1",
+        "time_stamp": 1578512902296873u64,
+        "confirmed_transaction_list": [
+            {
+                "version": "0x3",
+                "from": "hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd",
+                "to": "hxd5ace539bf910635c2fa0e9c185d2d3c8d52c4cc",
+                "value": "0xde0b6b3a7640000",
+                "stepLimit": "0x186a0",
+                "timestamp": "0x59b9e7a7d1a5a",
+                "nid": "0x1",
+                "signature": "VAia7YZ2Ji6igKWzjR2YsGa2m53nKPrfK7uXYW78QLE+ATehAVZPC40szvAiA6NEU5gCYB4c4qaQzqDh2ugcHgA=",
+                "txHash": "0x1b6133792cee1ab2e54ae68faf9f49daf81c7e46d68b1ca281acc718602c77dd"
+            },
+            {
+                "from": "hx54f7853dc6481b670caf69c5a27c7c8fe5be8269",
+                "to": "hx49a23bd156932485471f582897bf1bec5f875751",
+                "value": "0x56bc75e2d63100000",
+                "fee": "0x2386f26fc10000",
+                "timestamp": "1519709385120909",
+                "tx_hash": "375540830d475a73b704cf8dee9fa9eba2798f9d2af1fa55a85482e48daefd3b",
+                "signature": "bjarKeF3izGy469dpSciP3TT9caBQVYgHdaNgjY+8wJTOVSFm4o/ODXycFOdXUJcIwqvcE9If8x6Zmgt//XmkQE=",
+                "method": "icx_sendTransaction"
+            }
+        ],
+        "block_hash": "cf43b3fd45981431a0e64f79d07bfcf703e064b73b802c5f32834eec72142190",
+        "peer_id": "hx9c63f73d3c564a54d0eed84f90718b1ebed16f09"
+    })).unwrap();
+
+    assert_eq!(block.height, 3);
+    assert_eq!(block.confirmed_transaction_list.len(), 2);
+    assert_eq!(block.confirmed_transaction_list[0].step_limit, Some("0x186a0".to_string()));
+    assert_eq!(block.confirmed_transaction_list[1].tx_hash, "375540830d475a73b704cf8dee9fa9eba2798f9d2af1fa55a85482e48daefd3b");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decode_transaction_result() -> Result<(), ()> {
+    let result: TransactionResult = serde_json::from_value(json!({
+        "blockHash": "0x9a8a2e1bb3a8f3a0fdfb6d5d6b35db8d3f3e0ea9b1a0d8f8e52e9d7d1c0c7a5b",
+        "blockHeight": "0x4b8b4a",
+        "cumulativeStepUsed": "0x1d6fc",
+        "eventLogs": [
+            {
+                "data": ["0x1"],
+                "indexed": [
+                    "Transfer(Address,Address,int,bytes)",
+                    "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160",
+                    "hxb14e0c751899676a1a4e655a34063b42260f844b"
+                ],
+                "scoreAddress": "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa"
+            }
+        ],
+        "logsBloom": "0x00",
+        "status": "0x1",
+        "stepPrice": "0x2e90edd00",
+        "stepUsed": "0x1d6fc",
+        "to": "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa",
+        "txHash": "0x1b6133792cee1ab2e54ae68faf9f49daf81c7e46d68b1ca281acc718602c77dd",
+        "txIndex": "0x1"
+    })).unwrap();
+
+    assert!(result.is_success());
+    assert_eq!(result.event_logs[0].indexed[0], Some("Transfer(Address,Address,int,bytes)".to_string()));
+    assert!(result.failure.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decode_score_api() -> Result<(), ()> {
+    let api: ScoreApi = serde_json::from_value(json!([
+        {
+            "type": "function",
+            "name": "balanceOf",
+            "inputs": [{"name": "_owner", "type": "Address"}],
+            "outputs": [{"type": "int"}],
+            "readonly": "0x1"
+        },
+        {
+            "type": "eventlog",
+            "name": "Transfer",
+            "inputs": [
+                {"name": "_from", "type": "Address", "indexed": "0x1"},
+                {"name": "_to", "type": "Address", "indexed": "0x1"},
+                {"name": "_value", "type": "int", "indexed": "0x1"},
+                {"name": "_data", "type": "bytes"}
+            ]
+        },
+        {
+            "type": "fallback",
+            "name": "fallback",
+            "payable": "0x1"
+        }
+    ])).unwrap();
+
+    let balance_of = api.function("balanceOf").unwrap();
+    assert_eq!(balance_of.entry_type, ScoreApiType::Function);
+    assert_eq!(balance_of.outputs[0].param_type, "int");
+    assert!(balance_of.is_readonly());
+    assert!(!balance_of.is_payable());
+
+    let transfer = api.event("Transfer").unwrap();
+    assert_eq!(transfer.signature(), "Transfer(Address,Address,int,bytes)");
+    assert!(transfer.inputs[2].is_indexed());
+    assert!(!transfer.inputs[3].is_indexed());
+
+    assert!(api.fallback().unwrap().is_payable());
+    assert_eq!(api.functions().count(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use num_bigint::BigInt;
+use icon_sdk::error::IconError;
+use icon_sdk::rlp::{Decodable, Encodable, RlpItem, MAX_DEPTH};
+use icon_sdk::rlp_struct;
+
+#[derive(Debug, PartialEq)]
+struct Vote {
+    height: i64,
+    round: i32,
+    block_id: Vec<u8>,
+    voter: Option<String>,
+    amounts: Vec<BigInt>,
+    signatures: Vec<Option<Vec<u8>>>,
+}
+
+rlp_struct!(Vote { height, round, block_id, voter, amounts, signatures });
+
+#[tokio::test]
+async fn test_encode_decode() -> Result<(), ()> {
+    let item = RlpItem::List(vec![
+        RlpItem::Bytes(b"dog".to_vec()),
+        RlpItem::Bytes(vec![0x0f]),
+        RlpItem::Bytes(vec![0x80]),
+        RlpItem::List(vec![]),
+        RlpItem::Nil,
+        RlpItem::Bytes(vec![0xaa; 60]),
+    ]);
+
+    let encoded = item.encode();
+    assert_eq!(&encoded[..8], &[0xf8, 0x48, 0x83, b'd', b'o', b'g', 0x0f, 0x81]);
+    assert_eq!(&encoded[9..12], &[0xc0, 0xf8, 0x00]);
+    assert_eq!(&encoded[12..14], &[0xb8, 60]);
+    assert_eq!(RlpItem::decode(&encoded).unwrap(), item);
+
+    assert!(RlpItem::decode(&[0x83, b'd', b'o']).is_err());
+    assert!(RlpItem::decode(&[0x0f, 0x0f]).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_integers() -> Result<(), ()> {
+    for (value, bytes) in [
+        (0i64, vec![0x00]),
+        (127, vec![0x7f]),
+        (128, vec![0x00, 0x80]),
+        (-1, vec![0xff]),
+        (-129, vec![0xff, 0x7f]),
+        (0x5f5c0e4bd5c40, vec![0x05, 0xf5, 0xc0, 0xe4, 0xbd, 0x5c, 0x40]),
+    ] {
+        let item = value.to_rlp();
+        assert_eq!(item, RlpItem::Bytes(bytes));
+        assert_eq!(i64::from_rlp(&item).unwrap(), value);
+    }
+
+    assert_eq!(i64::from_rlp(&RlpItem::Bytes(vec![])).unwrap(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_struct() -> Result<(), ()> {
+    let vote = Vote {
+        height: 100,
+        round: 0,
+        block_id: vec![0xab; 32],
+        voter: None,
+        amounts: vec![BigInt::from(-1), BigInt::from(0x80)],
+        signatures: vec![Some(vec![0x01; 65]), None],
+    };
+
+    let encoded = vote.rlp_bytes();
+    assert_eq!(RlpItem::decode(&encoded).unwrap().as_list().unwrap()[3], RlpItem::Nil);
+    assert_eq!(Vote::from_rlp_bytes(&encoded).unwrap(), vote);
+
+    // Fields missing at the end are read as zero values
+    let short = RlpItem::List(vec![7i64.to_rlp()]).encode();
+    assert_eq!(Vote::from_rlp_bytes(&short).unwrap(), Vote {
+        height: 7,
+        round: 0,
+        block_id: vec![],
+        voter: None,
+        amounts: vec![],
+        signatures: vec![],
+    });
+
+    assert!(Vote::from_rlp_bytes(&RlpItem::Bytes(vec![0x01]).encode()).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_primitives() -> Result<(), ()> {
+    assert_eq!(u32::from_rlp_bytes(&300u32.rlp_bytes()).unwrap(), 300);
+    assert_eq!(u64::from_rlp_bytes(&u64::MAX.rlp_bytes()).unwrap(), u64::MAX);
+    assert!(u16::from_rlp_bytes(&70_000u32.rlp_bytes()).is_err());
+    assert!(u32::from_rlp_bytes(&(-1i64).rlp_bytes()).is_err());
+
+    assert!(bool::from_rlp_bytes(&true.rlp_bytes()).unwrap());
+    assert!(!bool::from_rlp_bytes(&RlpItem::Nil.encode()).unwrap());
+
+    assert_eq!(String::from_rlp_bytes(&"ICON".to_string().rlp_bytes()).unwrap(), "ICON");
+    assert_eq!(Option::<String>::from_rlp_bytes(&[0xf8, 0x00]).unwrap(), None);
+    assert_eq!(Vec::<i64>::from_rlp_bytes(&vec![1i64, -1].rlp_bytes()).unwrap(), vec![1, -1]);
+
+    Ok(())
+}
+
+// `depth` lists nested in each other
+fn nested_lists(depth: usize) -> Vec<u8> {
+    let mut headers = Vec::new();
+    let mut length = 1usize;
+    for _ in 1..depth {
+        let header = if length < 56 {
+            vec![0xc0 + length as u8]
+        } else {
+            let bytes: Vec<u8> = length.to_be_bytes().into_iter().skip_while(|byte| *byte == 0).collect();
+            let mut header = vec![0xf7 + bytes.len() as u8];
+            header.extend(bytes);
+            header
+        };
+        length += header.len();
+        headers.push(header);
+    }
+
+    headers.into_iter().rev().flatten().chain([0xc0]).collect()
+}
+
+#[tokio::test]
+async fn test_nesting_limit() -> Result<(), ()> {
+    assert!(RlpItem::decode(&nested_lists(MAX_DEPTH)).is_ok());
+    assert!(RlpItem::decode(&nested_lists(MAX_DEPTH + 1)).is_err());
+
+    // Deep enough to overflow the stack without the limit
+    match RlpItem::decode(&nested_lists(200_000)) {
+        Err(IconError::Rlp(message)) => assert!(message.contains("nested")),
+        _ => panic!("Expected a nesting error"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,251 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use base64::{Engine as _, engine::general_purpose as base64_encoder};
+use futures_util::StreamExt;
+use serde_json::{json, Value};
+use icon_sdk::event_filter::EventFilter;
+use icon_sdk::icon_service::IconService;
+use icon_sdk::logs_bloom::{event_bloom, LogsBloom};
+use icon_sdk::rlp::{Encodable, RlpItem};
+use icon_sdk::scanner::Scanner;
+
+const TOKEN: &str = "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa";
+const OTHER: &str = "cx9ab3078e72c8d9017194d17b34b1a47b661945ca";
+const FROM: &str = "hx8dc6ae3d93e60a2dddf80bfc5fb1cd16a2bf6160";
+const TO: &str = "hxb14e0c751899676a1a4e655a34063b42260f844b";
+const TRANSFER: &str = "Transfer(Address,Address,int,bytes)";
+
+fn parse_hex(value: &Value) -> u64 {
+    u64::from_str_radix(value.as_str().unwrap().trim_start_matches("0x"), 16).unwrap()
+}
+
+fn tx_hash(height: u64, index: u64) -> String {
+    format!("0x{:062x}{:02x}", height, index)
+}
+
+// Every block has two transactions, to `TOKEN` then to `OTHER`, each emitting a transfer of `height`.
+// Block 13 is missing.
+fn chain(method: &str, params: &Value) -> Result<Value, (i64, String)> {
+    match method {
+        "icx_getBlockByHeight" => {
+            let height = parse_hex(&params["height"]);
+            if height == 13 {
+                return Err((-31004, "NotFound".to_string()));
+            }
+            Ok(json!({
+                "version": "2.0",
+                "height": height,
+                "block_hash": format!("{:064x}", height),
+                "prev_block_hash": format!("{:064x}", height - 1),
+                "merkle_tree_root_hash": "",
+                "time_stamp": height,
+                "confirmed_transaction_list": [
+                    {"from": FROM, "to": TOKEN, "txHash": tx_hash(height, 0)},
+                    {"from": FROM, "to": OTHER, "txHash": tx_hash(height, 1)},
+                ],
+            }))
+        },
+        "icx_getTransactionResult" => {
+            let hash = params["txHash"].as_str().unwrap();
+            let height = u64::from_str_radix(&hash[2..64], 16).unwrap();
+            let index = u64::from_str_radix(&hash[64..], 16).unwrap();
+            let score = if index == 0 { TOKEN } else { OTHER };
+            Ok(json!({
+                "status": "0x1",
+                "to": score,
+                "txHash": hash,
+                "txIndex": format!("0x{:x}", index),
+                "blockHeight": format!("0x{:x}", height),
+                "blockHash": format!("0x{:064x}", height),
+                "cumulativeStepUsed": "0x0",
+                "stepUsed": "0x0",
+                "stepPrice": "0x0",
+                "eventLogs": [{
+                    "scoreAddress": score,
+                    "indexed": [TRANSFER, FROM, TO, format!("0x{:x}", height)],
+                    "data": ["0x"],
+                }],
+            }))
+        },
+        _ => Err((-32601, "MethodNotFound".to_string())),
+    }
+}
+
+async fn serve_chain() -> IconService {
+    IconService::new(Some(common::serve_rpc(chain).await))
+}
+
+// A header at `height` whose logs bloom holds the transfers of `TOKEN` only if `with_token`
+fn header(height: u64, with_token: bool) -> String {
+    let mut bloom = LogsBloom::new();
+    if with_token {
+        bloom.merge(&event_bloom(Some(TOKEN), TRANSFER, &[]).unwrap());
+    }
+    let header = RlpItem::List(vec![
+        2i64.to_rlp(),
+        height.to_rlp(),
+        0i64.to_rlp(),
+        RlpItem::Nil,
+        RlpItem::Bytes(vec![0x01; 32]),
+        RlpItem::Nil,
+        RlpItem::Nil,
+        RlpItem::Nil,
+        RlpItem::Nil,
+        bloom.to_rlp(),
+        RlpItem::Bytes(Vec::new()),
+    ]);
+
+    base64_encoder::STANDARD.encode(header.rlp_bytes())
+}
+
+#[tokio::test]
+async fn test_scan_transactions() -> Result<(), ()> {
+    let icon_service = serve_chain().await;
+
+    let results: Vec<_> = Scanner::new(&icon_service, 1, 12)
+        .concurrency(4)
+        .transactions(|transaction| transaction.to.as_deref() == Some(OTHER))
+        .scan()
+        .collect()
+        .await;
+
+    let heights: Vec<u64> = results.into_iter().map(|result| result.unwrap().height).collect();
+    assert_eq!(heights, (1..=12).collect::<Vec<u64>>());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_events() -> Result<(), ()> {
+    let icon_service = serve_chain().await;
+
+    let results: Vec<_> = Scanner::new(&icon_service, 1, 5)
+        .events(EventFilter::new(TRANSFER).address(TOKEN))
+        .scan()
+        .collect()
+        .await;
+
+    assert_eq!(results.len(), 5);
+    for (result, height) in results.into_iter().zip(1..) {
+        let result = result.unwrap();
+        assert_eq!(result.height, height);
+        assert_eq!(result.transaction.to.as_deref(), Some(TOKEN));
+        assert_eq!(result.events, vec![0]);
+    }
+
+    // No receipt of the range matches
+    let results: Vec<_> = Scanner::new(&icon_service, 1, 5)
+        .events(EventFilter::new(TRANSFER).indexed(vec![Some(TO)]))
+        .scan()
+        .collect()
+        .await;
+    assert!(results.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_stops_at_error() -> Result<(), ()> {
+    let icon_service = serve_chain().await;
+
+    let results: Vec<_> = Scanner::new(&icon_service, 10, 20)
+        .transactions(|transaction| transaction.to.as_deref() == Some(TOKEN))
+        .scan()
+        .collect()
+        .await;
+
+    // Blocks 10 to 12, then the missing block 13
+    assert_eq!(results.len(), 4);
+    assert!(results[..3].iter().all(|result| result.is_ok()));
+    assert!(results[3].is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_invalid_filter() -> Result<(), ()> {
+    let icon_service = IconService::new(Some("http://127.0.0.1:1/api/v3".to_string()));
+
+    let results: Vec<_> = Scanner::new(&icon_service, 1, 5)
+        .events(EventFilter::new("Transfer"))
+        .scan()
+        .collect()
+        .await;
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+async fn test_scan_concurrency_is_shared() -> Result<(), ()> {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+    let (current, max) = (in_flight.clone(), max_in_flight.clone());
+    let url = common::serve_rpc(move |method, params| {
+        let count = current.fetch_add(1, Ordering::SeqCst) + 1;
+        max.fetch_max(count, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(200));
+        current.fetch_sub(1, Ordering::SeqCst);
+        chain(method, params)
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let results: Vec<_> = Scanner::new(&icon_service, 1, 4)
+        .concurrency(2)
+        .scan()
+        .collect()
+        .await;
+
+    assert_eq!(results.len(), 8);
+    // Blocks and receipts share the limit
+    assert!(max_in_flight.load(Ordering::SeqCst) <= 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_skips_blocks_by_bloom() -> Result<(), ()> {
+    let fetched = Arc::new(Mutex::new(Vec::new()));
+    let receipts = fetched.clone();
+    let url = common::serve_rpc(move |method, params| match method {
+        // Only the receipts of even blocks hold transfers of `TOKEN`
+        "icx_getBlockHeaderByHeight" => {
+            let height = parse_hex(&params["height"]);
+            Ok(json!(header(height, (height - 1).is_multiple_of(2))))
+        },
+        "icx_getTransactionResult" => {
+            let hash = params["txHash"].as_str().unwrap();
+            receipts.lock().unwrap().push(u64::from_str_radix(&hash[2..64], 16).unwrap());
+            chain(method, params)
+        },
+        _ => chain(method, params),
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let results: Vec<_> = Scanner::new(&icon_service, 1, 6)
+        .events(EventFilter::new(TRANSFER).address(TOKEN))
+        .skip_by_bloom(true)
+        .scan()
+        .collect()
+        .await;
+
+    let heights: Vec<u64> = results.into_iter().map(|result| result.unwrap().height).collect();
+    assert_eq!(heights, vec![2, 4, 6]);
+    assert!(fetched.lock().unwrap().iter().all(|height| height % 2 == 0));
+
+    // Without opting in every block's receipts are fetched
+    fetched.lock().unwrap().clear();
+    let results: Vec<_> = Scanner::new(&icon_service, 1, 6)
+        .events(EventFilter::new(TRANSFER).address(TOKEN))
+        .scan()
+        .collect()
+        .await;
+    assert_eq!(results.len(), 6);
+    assert!(fetched.lock().unwrap().iter().any(|height| height % 2 == 1));
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use serde_json::json;
+use icon_sdk::icon_service::IconService;
+use icon_sdk::requests::BlockId;
+
+#[tokio::test]
+async fn test_get_score_status() -> Result<(), ()> {
+    let url = common::serve_rpc(|method, params| match method {
+        "icx_getScoreStatus" => {
+            assert_eq!(params["address"], json!("cx9ab3078e72c8d9017194d17b34b1a47b661945ca"));
+            match params.get("height") {
+                None => Ok(json!({
+                    "owner": "hxb14e0c751899676a1a4e655a34063b42260f844b",
+                    "current": {
+                        "type": "java",
+                        "codeHash": "0x1a2b",
+                        "deployTxHash": "0x3c4d",
+                        "status": "active"
+                    },
+                    "deposit": {"availableDeposit": "0x0"},
+                    "useSystemDeposit": "0x0"
+                })),
+                Some(height) => {
+                    assert_eq!(height, &json!("0x64"));
+                    Ok(json!({
+                        "owner": "hxb14e0c751899676a1a4e655a34063b42260f844b",
+                        "current": {"type": "python", "codeHash": "0x5e6f", "deployTxHash": "0x7a8b"},
+                        "blocked": "0x1"
+                    }))
+                },
+            }
+        },
+        _ => Err((-32601, "method not found".to_string())),
+    }).await;
+    let icon_service = IconService::new(Some(url));
+
+    let status = icon_service.get_score_status("cx9ab3078e72c8d9017194d17b34b1a47b661945ca", BlockId::Latest).await.unwrap();
+    assert_eq!(status.owner, "hxb14e0c751899676a1a4e655a34063b42260f844b");
+    let current = status.current.as_ref().unwrap();
+    assert_eq!(current.contract_type, "java");
+    assert_eq!(current.code_hash, "0x1a2b");
+    assert_eq!(current.deploy_tx_hash, "0x3c4d");
+    assert_eq!(status.deposit, Some(json!({"availableDeposit": "0x0"})));
+    assert!(status.is_active());
+
+    let status = icon_service.get_score_status("cx9ab3078e72c8d9017194d17b34b1a47b661945ca", BlockId::Height(100)).await.unwrap();
+    assert_eq!(status.current.as_ref().unwrap().code_hash, "0x5e6f");
+    assert!(status.is_blocked());
+    assert!(!status.is_disabled());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_queries_at_height() -> Result<(), ()> {
+    let url = common::serve_rpc(|method, params| {
+        let height = params.get("height").and_then(|height| height.as_str()).unwrap_or("latest").to_string();
+        match method {
+            "icx_getBalance" => Ok(json!(if height == "0x64" { "0x1" } else { "0x2" })),
+            "icx_call" => Ok(json!(height)),
+            "icx_getScoreApi" => {
+                assert_eq!(height, "0x64");
+                Ok(json!([{"type": "function", "name": "name", "inputs": [], "outputs": [{"type": "str"}], "readonly": "0x1"}]))
+            },
+            _ => Err((-32601, "method not found".to_string())),
+        }
+    }).await;
+    let icon_service = IconService::new(Some(url));
+    let address = "cx9ab3078e72c8d9017194d17b34b1a47b661945ca";
+
+    let balance = icon_service.get_balance_at(address, BlockId::Height(100)).await.unwrap();
+    assert_eq!(balance["result"], "0x1");
+    let balance = icon_service.get_balance(address).await.unwrap();
+    assert_eq!(balance["result"], "0x2");
+
+    let result = icon_service.call_at(address, json!({"method": "name"}), 100.into()).await.unwrap();
+    assert_eq!(result["result"], "0x64");
+    let result = icon_service.call(address, json!({"method": "name"})).await.unwrap();
+    assert_eq!(result["result"], "latest");
+
+    let score_api = icon_service.get_score_api_at(address, BlockId::Height(100)).await.unwrap();
+    assert!(score_api.function("name").is_some());
+
+    Ok(())
+}
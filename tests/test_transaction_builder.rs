@@ -0,0 +1,149 @@
+use serde_json::{json, Map};
+use icon_sdk::error::IconError;
+use icon_sdk::transaction::SignedTransaction;
+use icon_sdk::transaction_builder::TransactionBuilder;
+use icon_sdk::wallet::Wallet;
+
+#[tokio::test]
+async fn test_build_signed_offline() -> Result<(), ()> {
+    let wallet = Wallet::new(Some("f4ade1ff528c9e0bf10d35909e3486ef6ce88df8a183fc1cc2c65bfa9a53d3fd".to_string()));
+    let signed_transaction = TransactionBuilder::new_offline()
+        .from(wallet.get_public_address().as_str())
+        .to("hxf8689d6c4c8f333651469fdea2ac59a18f6c2421")
+        .value("1.31231232")
+        .version("0x3")
+        .nid("0x2")
+        .timestamp()
+        .nonce("0x1")
+        .step_limit("0x186a0")
+        .sign(wallet.get_private_key().as_str())
+        .build_signed()
+        .unwrap();
+
+    assert_eq!(signed_transaction.params()["from"], "hxb14e0c751899676a1a4e655a34063b42260f844b");
+    assert_eq!(signed_transaction.params()["value"], "0x1236451a23f80000");
+    assert!(signed_transaction.params().contains_key("signature"));
+
+    let serialized = serde_json::to_string(&signed_transaction).unwrap();
+    let deserialized: SignedTransaction = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, signed_transaction);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_signed_transaction_hash() -> Result<(), ()> {
+    let wallet = Wallet::new(Some("f4ade1ff528c9e0bf10d35909e3486ef6ce88df8a183fc1cc2c65bfa9a53d3fd".to_string()));
+    let mut timestamp = Map::new();
+    timestamp.insert("timestamp".to_string(), json!("0x5f5c0e4bd5c40"));
+
+    let signed_transaction = TransactionBuilder::new_offline()
+        .from(wallet.get_public_address().as_str())
+        .to("hxf8689d6c4c8f333651469fdea2ac59a18f6c2421")
+        .value("1.31231232")
+        .version("0x3")
+        .nid("0x2")
+        .set_params(&timestamp)
+        .nonce("0x1")
+        .step_limit("0x186a0")
+        .sign(wallet.get_private_key().as_str())
+        .build_signed()
+        .unwrap();
+
+    assert_eq!(signed_transaction.tx_hash(), "0xb15ffc00ff46abd6bd8a3ec7d13c097c6f0c8199f9fc570cb50263c53eb2f489");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_build_reports_validation_errors() -> Result<(), ()> {
+    let res = TransactionBuilder::new_offline()
+        .method("icx_sendTransaction")
+        .from("hxb14e0c751899676a1a4e655a34063b42260f844b")
+        .value("not a number")
+        .sign("not a key")
+        .build();
+
+    match res {
+        Err(IconError::InvalidTransaction(errors)) => {
+            assert!(errors.contains(&"invalid value: not a number".to_string()));
+            assert!(errors.contains(&"missing to".to_string()));
+            assert!(errors.contains(&"missing stepLimit".to_string()));
+            assert!(!errors.contains(&"missing from".to_string()));
+            // The key is still checked, though nothing is signed
+            assert!(errors.contains(&"invalid private key".to_string()));
+        },
+        _ => panic!("Expected validation errors"),
+    }
+
+    let res = TransactionBuilder::new_offline()
+        .method("icx_sendTransaction")
+        .from("hxb14e0c751899676a1a4e655a34063b42260f844b")
+        .value("0x1")
+        .sign("not a key")
+        .build();
+
+    match res {
+        Err(IconError::InvalidTransaction(errors)) => assert!(errors.contains(&"invalid private key".to_string())),
+        _ => panic!("Expected validation errors"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_build_signed_requires_signature() -> Result<(), ()> {
+    let res = TransactionBuilder::new_offline()
+        .from("hxb14e0c751899676a1a4e655a34063b42260f844b")
+        .to("hxf8689d6c4c8f333651469fdea2ac59a18f6c2421")
+        .value("0x1")
+        .version("0x3")
+        .nid("0x2")
+        .timestamp()
+        .step_limit("0x186a0")
+        .build_signed();
+
+    match res {
+        Err(IconError::InvalidTransaction(errors)) => assert_eq!(errors, vec!["missing signature".to_string()]),
+        _ => panic!("Expected missing signature error"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_build_checks_data_and_hex_fields() -> Result<(), ()> {
+    let builder = || TransactionBuilder::new_offline()
+        .method("icx_sendTransaction")
+        .from("hxb14e0c751899676a1a4e655a34063b42260f844b")
+        .to("cx0000000000000000000000000000000000000000")
+        .version("0x3")
+        .nid("0x2")
+        .timestamp()
+        .step_limit("0x186a0");
+    let errors = |res: Result<_, IconError>| match res {
+        Err(IconError::InvalidTransaction(errors)) => errors,
+        _ => panic!("Expected validation errors"),
+    };
+
+    let res = builder().call(json!({"params": {}})).build();
+    assert_eq!(errors(res), vec!["missing data.method".to_string()]);
+
+    let mut deploy = Map::new();
+    deploy.insert("dataType".to_string(), json!("deploy"));
+    deploy.insert("data".to_string(), json!({"contentType": "application/java"}));
+    let res = builder().set_params(&deploy).build();
+    assert_eq!(errors(res), vec!["missing data.content".to_string()]);
+
+    let mut message = Map::new();
+    message.insert("dataType".to_string(), json!("message"));
+    let res = builder().set_params(&message).build();
+    assert_eq!(errors(res), vec!["missing data".to_string()]);
+
+    let res = builder().value("0xZZ").nonce("0x").build();
+    assert_eq!(errors(res), vec!["invalid value: 0xZZ".to_string(), "invalid nonce: 0x".to_string()]);
+
+    assert!(builder().call(json!({"method": "transfer"})).value("0x1").build().is_ok());
+
+    Ok(())
+}
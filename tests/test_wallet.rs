@@ -1,4 +1,4 @@
-use icon_sdk::wallet::Wallet;
+use icon_sdk::wallet::{verify_message, Wallet};
 
 #[tokio::test]
 async fn test_wallet() -> Result<(), ()> {
@@ -7,3 +7,16 @@ async fn test_wallet() -> Result<(), ()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_sign_and_verify_message() -> Result<(), ()> {
+    let wallet = Wallet::new(Some("f4ade1ff528c9e0bf10d35909e3486ef6ce88df8a183fc1cc2c65bfa9a53d3fd".to_string()));
+    let signature = wallet.sign_message(b"Login challenge 1234").unwrap();
+
+    assert!(verify_message(&wallet.get_public_address(), b"Login challenge 1234", &signature));
+    assert!(!verify_message(&wallet.get_public_address(), b"Login challenge 1235", &signature));
+    assert!(!verify_message("hx70e8eeb5d23ab18a828ec95f769db6d953e5f0fd", b"Login challenge 1234", &signature));
+    assert!(!verify_message(&wallet.get_public_address(), b"Login challenge 1234", "not a signature"));
+
+    Ok(())
+}
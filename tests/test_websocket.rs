@@ -0,0 +1,85 @@
+mod common;
+
+use futures_util::StreamExt;
+use serde_json::json;
+use tokio::net::TcpListener;
+use icon_sdk::event_filter::EventFilter;
+use icon_sdk::icon_service::IconService;
+use icon_sdk::websocket::EventMessage;
+
+#[tokio::test]
+async fn test_subscribe_blocks() -> Result<(), ()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let icon_service = IconService::new(Some(format!("http://{}/api/v3", listener.local_addr().unwrap())));
+
+    tokio::spawn(common::serve_websocket(listener, vec![
+        (json!({"height": "0x5"}), json!({"code": 0}), vec![
+            json!({"hash": "0x05", "height": "0x5"}),
+            json!({"hash": "0x06", "height": "0x6"}),
+        ]),
+        // Reconnects after the last block seen
+        (json!({"height": "0x7"}), json!({"code": 0}), vec![
+            json!({"hash": "0x07", "height": "0x7"}),
+        ]),
+    ]));
+
+    let blocks: Vec<_> = icon_service.subscribe_blocks(5).take(3).collect().await;
+    let heights: Vec<String> = blocks.into_iter().map(|block| block.unwrap().height).collect();
+    assert_eq!(heights, vec!["0x5", "0x6", "0x7"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_events() -> Result<(), ()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let icon_service = IconService::new(Some(format!("http://{}/api/v3", listener.local_addr().unwrap())));
+    let filter = EventFilter::new("Transfer(Address,Address,int,bytes)")
+        .address("cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa");
+
+    tokio::spawn(common::serve_websocket(listener, vec![
+        (json!({"height": "0xa", "addr": "cx273548dff8bb77ffaac5a342c4c04aeae0bc48fa", "event": "Transfer(Address,Address,int,bytes)", "logs": "0x1"}), json!({"code": 0}), vec![
+            json!({"hash": "0x0a", "height": "0xa", "index": "0x1", "events": ["0x0"]}),
+            json!({"progress": "0x14"}),
+            json!({"hash": "0x15", "height": "0x15", "index": "0x0", "events": ["0x0"]}),
+        ]),
+        // Resumes at the block of the last event and skips what was already delivered
+        (json!({"height": "0x15"}), json!({"code": 0}), vec![
+            json!({"hash": "0x15", "height": "0x15", "index": "0x0", "events": ["0x0"]}),
+            json!({"hash": "0x15", "height": "0x15", "index": "0x2", "events": ["0x1"]}),
+        ]),
+    ]));
+
+    let messages: Vec<EventMessage> = icon_service.subscribe_events(filter, 10)
+        .take(4)
+        .map(|message| message.unwrap())
+        .collect()
+        .await;
+
+    match &messages[..] {
+        [EventMessage::Event(first), EventMessage::Progress(20), EventMessage::Event(second), EventMessage::Event(third)] => {
+            assert_eq!((first.height.as_str(), first.index.as_str()), ("0xa", "0x1"));
+            assert_eq!((second.height.as_str(), second.index.as_str()), ("0x15", "0x0"));
+            assert_eq!((third.height.as_str(), third.index.as_str()), ("0x15", "0x2"));
+        },
+        other => panic!("Unexpected messages: {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscription_rejected() -> Result<(), ()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let icon_service = IconService::new(Some(format!("http://{}/api/v3", listener.local_addr().unwrap())));
+
+    tokio::spawn(common::serve_websocket(listener, vec![
+        (json!({"height": "0x1"}), json!({"code": -32602, "message": "invalid height"}), vec![]),
+    ]));
+
+    let messages: Vec<_> = icon_service.subscribe_blocks(1).collect().await;
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].is_err());
+
+    Ok(())
+}